@@ -1,19 +1,18 @@
 #[macro_use]
 extern crate criterion;
 
-use ark_ec::pairing::Pairing;
-use ark_ec::{CurveGroup, ScalarMul};
+use ark_ec::CurveGroup;
 use ark_ff::{FftField, Field};
 use ark_poly::univariate::DensePolynomial;
 use ark_poly::{DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain};
-use ark_std::test_rng;
 use ark_std::UniformRand;
 use criterion::measurement::Measurement;
 use criterion::BenchmarkGroup;
 use criterion::{BenchmarkId, Criterion};
+use zkalc_common::config;
 
 fn bench_msm<G: CurveGroup, M: Measurement>(c: &mut BenchmarkGroup<'_, M>, group_name: &str) {
-    let rng = &mut test_rng();
+    let rng = &mut config::rng();
 
     for logsize in 1..=21 {
         let size = 1 << logsize;
@@ -37,24 +36,8 @@ fn bench_msm<G: CurveGroup, M: Measurement>(c: &mut BenchmarkGroup<'_, M>, group
     }
 }
 
-fn bench_multi_pairing<P: Pairing, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let rng = &mut test_rng();
-    for logsize in 1..=18 {
-        let size = 1 << logsize;
-        let g1s = (0..size)
-            .map(|_| P::G1::rand(rng).into_affine())
-            .collect::<Vec<_>>();
-        let g2s = (0..size)
-            .map(|_| P::G2::rand(rng).into_affine())
-            .collect::<Vec<_>>();
-        c.bench_with_input(BenchmarkId::new("msm/Gt", size), &logsize, |b, _| {
-            b.iter(|| P::multi_pairing(&g1s, &g2s))
-        });
-    }
-}
-
 fn bench_sum_of_products<F: Field, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let rng = &mut test_rng();
+    let rng = &mut config::rng();
     c.bench_function("msm/ff", |b| {
         const SIZE: usize = 256;
         let lhs: [F; SIZE] = (0..SIZE)
@@ -71,27 +54,8 @@ fn bench_sum_of_products<F: Field, M: Measurement>(c: &mut BenchmarkGroup<'_, M>
     });
 }
 
-fn bench_mul<G: ScalarMul, M: Measurement>(c: &mut BenchmarkGroup<'_, M>, group_name: &str) {
-    let rng = &mut test_rng();
-    c.bench_function(format!("mul_{}", group_name), |b| {
-        const SIZE: usize = 256;
-        let lhs = G::rand(rng);
-        let rhs = G::ScalarField::rand(rng);
-        b.iter(|| lhs * &rhs)
-    });
-}
-
-fn bench_pairing<P: Pairing, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
-    c.bench_function("pairing", |r| {
-        let a = P::G1::rand(&mut rng).into_affine();
-        let b = P::G2::rand(&mut rng).into_affine();
-        r.iter(|| P::pairing(a, b))
-    });
-}
-
 fn bench_fft<F: FftField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     for logsize in 1..=21 {
         let degree = 1 << logsize;
         let domain = GeneralEvaluationDomain::<F>::new(degree).unwrap();
@@ -104,46 +68,31 @@ fn bench_fft<F: FftField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
     }
 }
 
+// The core BN254 operations are benchmarked in bench_arkworks_bn254.rs
 fn bench_bn254(c: &mut Criterion) {
-    use ark_bn254::{Bn254, Fr, G1Projective as G1, G2Projective as G2};
-    type Gt = ark_ec::pairing::PairingOutput<Bn254>;
+    use ark_bn254::Fr;
 
     let mut group = c.benchmark_group("bn254");
-    bench_msm::<G1, _>(&mut group, "G1");
-    bench_msm::<G2, _>(&mut group, "G2");
-    bench_mul::<Gt, _>(&mut group, "Gt");
-    bench_multi_pairing::<Bn254, _>(&mut group);
-    bench_pairing::<Bn254, _>(&mut group);
     bench_sum_of_products::<Fr, _>(&mut group);
     bench_fft::<Fr, _>(&mut group);
     group.finish();
 }
 
+// The core BLS12-381 operations are benchmarked in bench_bls12_381.rs
 fn bench_bls12_381(c: &mut Criterion) {
-    use ark_bls12_381::{Bls12_381, Fr, G1Projective, G2Projective};
-    type Gt = ark_ec::pairing::PairingOutput<Bls12_381>;
+    use ark_bls12_381::Fr;
 
     let mut group = c.benchmark_group("bls12_381");
-    bench_msm::<G1Projective, _>(&mut group, "G1");
-    bench_msm::<G2Projective, _>(&mut group, "G2");
-    bench_mul::<Gt, _>(&mut group, "Gt");
-    bench_multi_pairing::<Bls12_381, _>(&mut group);
-    bench_pairing::<Bls12_381, _>(&mut group);
     bench_sum_of_products::<Fr, _>(&mut group);
     bench_fft::<Fr, _>(&mut group);
     group.finish();
 }
 
+// The core BLS12-377 operations are benchmarked in bench_bls12_377.rs
 fn bench_bls12_377(c: &mut Criterion) {
-    use ark_bls12_377::{Bls12_377, Fr, G1Projective, G2Projective};
-    type Gt = ark_ec::pairing::PairingOutput<Bls12_377>;
+    use ark_bls12_377::Fr;
 
     let mut group = c.benchmark_group("bls12_377");
-    bench_msm::<G1Projective, _>(&mut group, "G1");
-    bench_msm::<G2Projective, _>(&mut group, "G2");
-    bench_mul::<Gt, _>(&mut group, "Gt");
-    bench_multi_pairing::<Bls12_377, _>(&mut group);
-    bench_pairing::<Bls12_377, _>(&mut group);
     bench_sum_of_products::<Fr, _>(&mut group);
     bench_fft::<Fr, _>(&mut group);
     group.finish();
@@ -0,0 +1,15 @@
+//! BN254 benchmarks mirroring the operation set and benchmark ids of the blstrs backend.
+
+#[macro_use]
+extern crate criterion;
+
+use ark_bn254::Bn254;
+use arkworks_benchmarks::benches::bench_pairing_curve;
+use criterion::Criterion;
+
+fn bench_bn254(c: &mut Criterion) {
+    bench_pairing_curve::<Bn254>(c);
+}
+
+criterion_group!(benches, bench_bn254);
+criterion_main!(benches);
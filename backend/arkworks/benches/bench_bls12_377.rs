@@ -0,0 +1,15 @@
+//! BLS12-377 benchmarks mirroring the operation set and benchmark ids of the blstrs backend.
+
+#[macro_use]
+extern crate criterion;
+
+use ark_bls12_377::Bls12_377;
+use arkworks_benchmarks::benches::bench_pairing_curve;
+use criterion::Criterion;
+
+fn bench_bls12_377(c: &mut Criterion) {
+    bench_pairing_curve::<Bls12_377>(c);
+}
+
+criterion_group!(benches, bench_bls12_377);
+criterion_main!(benches);
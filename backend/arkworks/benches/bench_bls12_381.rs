@@ -0,0 +1,16 @@
+//! BLS12-381 benchmarks mirroring the operation set and benchmark ids of the blstrs backend,
+//! so that the two libraries can be compared on the same curve.
+
+#[macro_use]
+extern crate criterion;
+
+use ark_bls12_381::Bls12_381;
+use arkworks_benchmarks::benches::bench_pairing_curve;
+use criterion::Criterion;
+
+fn bench_bls12_381(c: &mut Criterion) {
+    bench_pairing_curve::<Bls12_381>(c);
+}
+
+criterion_group!(benches, bench_bls12_381);
+criterion_main!(benches);
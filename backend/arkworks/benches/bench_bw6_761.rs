@@ -0,0 +1,18 @@
+//! BW6-761 benchmarks mirroring the operation set and benchmark ids of the blstrs backend.
+//!
+//! BW6-761 is the outer curve of BLS12-377: its scalar field is the base field
+//! of BLS12-377, so that BLS12-377 proofs can be verified in a BW6-761 circuit.
+
+#[macro_use]
+extern crate criterion;
+
+use ark_bw6_761::BW6_761;
+use arkworks_benchmarks::benches::bench_pairing_curve;
+use criterion::Criterion;
+
+fn bench_bw6_761(c: &mut Criterion) {
+    bench_pairing_curve::<BW6_761>(c);
+}
+
+criterion_group!(benches, bench_bw6_761);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! The full benchmark set of an arkworks pairing engine: the benchmarks shared
+//! by all backends plus the Gt operations and pairing products. Each curve's
+//! bench file only names its engine, so a sibling curve, e.g. BW6-761 as the
+//! outer curve of BLS12-377, needs nothing but a [`CurveName`] impl.
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::CurveGroup;
+use ark_std::UniformRand;
+use criterion::measurement::Measurement;
+use criterion::{black_box, BatchSize, BenchmarkGroup, BenchmarkId, Criterion};
+use zkalc_common::benches::{bench_field_ops, bench_group_ops, bench_pairing_ops};
+use zkalc_common::config;
+
+use crate::{Ark, CurveName};
+
+pub fn bench_pairing_curve<P: Pairing + CurveName>(c: &mut Criterion) {
+    bench_field_ops::<Ark<P>>(c);
+    bench_group_ops::<Ark<P>>(c);
+    bench_pairing_ops::<Ark<P>>(c);
+
+    let mut group = c.benchmark_group(P::NAME);
+    bench_gt::<P, _>(&mut group);
+    bench_pairing_product::<P, _>(&mut group);
+    group.finish();
+}
+
+// Gt is written additively in arkworks: `+` is the Fp12 multiplication and
+// `* ScalarField` is the exponentiation.
+fn bench_gt<P: Pairing, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
+    let mut rng = config::rng();
+    c.bench_function("gt/mul", |b| {
+        let lhs = PairingOutput::<P>::rand(&mut rng);
+        let rhs = PairingOutput::<P>::rand(&mut rng);
+        b.iter(|| black_box(lhs) + black_box(rhs))
+    });
+    c.bench_function("gt/exp", |b| {
+        let lhs = PairingOutput::<P>::rand(&mut rng);
+        let rhs = P::ScalarField::rand(&mut rng);
+        b.iter(|| black_box(lhs) * black_box(rhs))
+    });
+}
+
+// The arkworks `msm/Gt` series has always swept up to 2^18 pairings.
+const PAIRING_MAX_LOGSIZE: usize = 18;
+
+// `msm/Gt` takes G2 in affine form, so its preparation is timed as it always
+// was; `msm/Gt/prepared` prepares G2 outside the timed loop, as the blstrs
+// backend's `msm/Gt` does.
+fn bench_pairing_product<P: Pairing, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
+    let mut rng = config::rng();
+    for d in config::pairing_logsizes_up_to(PAIRING_MAX_LOGSIZE) {
+        c.sample_size(config::sample_size(d));
+        let size = 1 << d;
+        let g1s = (0..size)
+            .map(|_| P::G1::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        let g2s = (0..size)
+            .map(|_| P::G2::rand(&mut rng).into_affine())
+            .collect::<Vec<_>>();
+        c.bench_with_input(BenchmarkId::new("msm/Gt", size), &d, |b, _| {
+            b.iter(|| P::multi_pairing(&g1s, &g2s))
+        });
+
+        let prepared = g2s
+            .iter()
+            .map(|g2| P::G2Prepared::from(*g2))
+            .collect::<Vec<_>>();
+        c.bench_with_input(BenchmarkId::new("msm/Gt/prepared", size), &d, |b, _| {
+            b.iter_batched(
+                || prepared.clone(),
+                |prepared| P::multi_pairing(&g1s, prepared),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+}
@@ -0,0 +1,104 @@
+//! Support code shared by the arkworks benchmarks.
+
+pub mod benches;
+
+use std::marker::PhantomData;
+
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::{CurveGroup, VariableBaseMSM};
+use ark_ff::Field;
+use ark_std::UniformRand;
+use rand::RngCore;
+use zkalc_common::config;
+use zkalc_common::curve::{Curve, PairingCurve};
+
+/// The name of an arkworks curve, as used in benchmark ids.
+pub trait CurveName {
+    const NAME: &'static str;
+    /// See [`Curve::MSM_MAX_LOGSIZE`].
+    const MSM_MAX_LOGSIZE: usize = config::MSM_MAX_LOGSIZE;
+}
+
+/// An arkworks pairing engine as a [`zkalc_common::curve::Curve`], for the
+/// benchmarks shared by all backends.
+pub struct Ark<P>(PhantomData<P>);
+
+impl<P: Pairing + CurveName> Curve for Ark<P> {
+    const NAME: &'static str = P::NAME;
+    const MSM_MAX_LOGSIZE: usize = P::MSM_MAX_LOGSIZE;
+
+    type Scalar = P::ScalarField;
+    type G1 = P::G1;
+    type G1Base = P::G1Affine;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> P::ScalarField {
+        P::ScalarField::rand(rng)
+    }
+
+    fn rand_g1(rng: &mut impl RngCore) -> P::G1 {
+        P::G1::rand(rng)
+    }
+
+    fn invert(x: &P::ScalarField) -> Option<P::ScalarField> {
+        x.inverse()
+    }
+
+    fn g1_base(p: &P::G1) -> P::G1Affine {
+        p.into_affine()
+    }
+
+    fn msm_g1(bases: &[P::G1Affine], scalars: &[P::ScalarField]) -> P::G1 {
+        P::G1::msm(bases, scalars).unwrap()
+    }
+}
+
+impl<P: Pairing + CurveName> PairingCurve for Ark<P> {
+    type G2 = P::G2;
+    type G2Base = P::G2Affine;
+    type G1Affine = P::G1Affine;
+    type G2Affine = P::G2Affine;
+    type Gt = PairingOutput<P>;
+
+    fn rand_g2(rng: &mut impl RngCore) -> P::G2 {
+        P::G2::rand(rng)
+    }
+
+    fn g2_base(p: &P::G2) -> P::G2Affine {
+        p.into_affine()
+    }
+
+    fn msm_g2(bases: &[P::G2Affine], scalars: &[P::ScalarField]) -> P::G2 {
+        P::G2::msm(bases, scalars).unwrap()
+    }
+
+    fn g1_to_affine(p: &P::G1) -> P::G1Affine {
+        p.into_affine()
+    }
+
+    fn g2_to_affine(p: &P::G2) -> P::G2Affine {
+        p.into_affine()
+    }
+
+    fn pairing(a: &P::G1Affine, b: &P::G2Affine) -> PairingOutput<P> {
+        P::pairing(a, b)
+    }
+}
+
+impl CurveName for ark_bn254::Bn254 {
+    const NAME: &'static str = "bn254";
+}
+
+impl CurveName for ark_bls12_381::Bls12_381 {
+    const NAME: &'static str = "bls12_381";
+}
+
+impl CurveName for ark_bls12_377::Bls12_377 {
+    const NAME: &'static str = "bls12_377";
+}
+
+// Its base field has 761 bits, so MSMs are several times slower than on the
+// inner curve BLS12-377
+impl CurveName for ark_bw6_761::BW6_761 {
+    const NAME: &'static str = "bw6_761";
+    const MSM_MAX_LOGSIZE: usize = 16;
+}
@@ -1,118 +1,1284 @@
 #![allow(non_snake_case)]
 
-use blstrs::{Bls12, G2Prepared, Gt};
-use blstrs::{G1Affine, G1Projective, G2Projective, Scalar};
+use blstrs::{Bls12, Fp12, Fp2, G2Prepared, Gt};
+use blstrs::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use blstrs_benchmarks::affine;
+use blstrs_benchmarks::cofactor::{
+    clear_cofactor_g1, clear_cofactor_g2, random_g1_point, random_g2_point,
+};
+use blstrs_benchmarks::curve::Bls12_381;
+use blstrs_benchmarks::field::{
+    batch_invert, from_bytes_wide, from_montgomery, powers, to_montgomery,
+};
+use blstrs_benchmarks::final_exp::{easy_part, hard_part};
+use blstrs_benchmarks::hash::{encode_to_g1, encode_to_g2, hash_to_field};
+use blstrs_benchmarks::transcript::Transcript;
 use criterion::*;
-use group::{Curve, Group};
+use ff::{Field, PrimeField};
+use group::prime::{PrimeCurve, PrimeCurveAffine};
+use group::{Curve, Group, UncompressedEncoding};
 use pairing_lib::{MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
-use std::ops::Add;
-use ff::Field;
+use rand::Rng;
+use rayon::prelude::*;
+use subtle::{Choice, ConditionallySelectable, CtOption};
+use zkalc_common::benches::{bench_field_ops, bench_group_ops, bench_pairing_ops, InputPool};
+use zkalc_common::config;
+use zkalc_common::msm::{msm, msm_g2};
 
-fn bench_mul(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
+fn bench_square(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/square_ff", |b| {
+        let a = Scalar::random(&mut rng);
+        b.iter(|| black_box(a).square())
+    });
+}
 
-    c.bench_function("bls12_381/mul_ff", |b| {
+fn bench_sub(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/sub_ff", |b| {
         let lhs = Scalar::random(&mut rng);
         let rhs = Scalar::random(&mut rng);
-        b.iter(|| black_box(lhs) * black_box(rhs))
+        b.iter(|| black_box(lhs) - black_box(rhs))
+    });
+}
+
+fn bench_neg(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/neg_ff", |b| {
+        let a = Scalar::random(&mut rng);
+        b.iter(|| -black_box(a))
     });
+}
+
+/// Little-endian 64-bit limbs of a scalar, the exponent format of `Field::pow`.
+fn to_u64_limbs(s: &Scalar) -> [u64; 4] {
+    let bytes = s.to_bytes_le();
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+fn bench_pow(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/pow_ff/255_bits", |b| {
+        let base = Scalar::random(&mut rng);
+        // A full-width exponent, i.e. the worst case bit length
+        let exp = to_u64_limbs(&Scalar::random(&mut rng));
+        b.iter(|| black_box(base).pow(black_box(exp)))
+    });
+}
+
+// The portable inversion a^(r-2), next to the shared `bls12_381/invert` bench
+// of blst's dedicated routine, in constant and in variable time. blst itself
+// only has constant-time inversion and scalar multiplication (the vartime
+// entry points are MSMs), so this `pow`/`pow_vartime` pair is the only place
+// where the overhead of constant time can be read off.
+fn bench_invert_fermat(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/invert");
+    // r - 2, since -2 = r - 2 mod r
+    let exp = to_u64_limbs(&-Scalar::from(2));
+    let mut pool = InputPool::new(|| Scalar::random(&mut rng));
+    let x = *pool.next_input();
+    assert_eq!(x.pow(exp), x.invert().unwrap());
+    assert_eq!(x.pow_vartime(exp), x.invert().unwrap());
+    group.bench_function("fermat_ct", |b| b.iter(|| pool.next_input().pow(exp)));
+    group.bench_function("fermat_vt", |b| {
+        b.iter(|| pool.next_input().pow_vartime(exp))
+    });
+    group.finish()
+}
+
+fn bench_sqrt(c: &mut Criterion) {
+    let mut rng = config::rng();
+    // Square random elements so that a root is guaranteed to exist
+    let mut pool = InputPool::new(|| Scalar::random(&mut rng).square());
+    c.bench_function("bls12_381/sqrt_ff", |b| b.iter(|| pool.next_input().sqrt()));
+}
+
+fn bench_is_square(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/is_square_ff", |b| {
+        let x = Scalar::random(&mut rng);
+        b.iter(|| black_box(x).is_quad_res())
+    });
+}
+
+// The tower of extension fields that the pairing is computed in.
+fn bench_extension_field<F: Field>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group(format!("bls12_381/{}", name));
+    let a = F::random(&mut rng);
+    let b = F::random(&mut rng);
+    group.bench_function("mul", |r| r.iter(|| black_box(a) * black_box(b)));
+    group.bench_function("invert", |r| r.iter(|| black_box(a).invert().unwrap()));
+    group.finish()
+}
+
+fn bench_fp2(c: &mut Criterion) {
+    bench_extension_field::<Fp2>(c, "fp2")
+}
+
+fn bench_fp12(c: &mut Criterion) {
+    bench_extension_field::<Fp12>(c, "fp12")
+}
+
+/// Textbook left-to-right double-and-add over the 255 bits of `s`.
+fn double_and_add<G: Group>(p: &G, s: &Scalar) -> G {
+    let bytes = s.to_bytes_le();
+    let mut acc = G::identity();
+    for i in (0..Scalar::NUM_BITS as usize).rev() {
+        acc = acc.double();
+        if (bytes[i / 8] >> (i % 8)) & 1 == 1 {
+            acc += p;
+        }
+    }
+    acc
+}
+
+// blstrs' `G1Projective * Scalar` is blst's `blst_p1_mult`, which already
+// splits full-size scalars with the GLV endomorphism. So `G1/glv` measures the
+// same code path as the plain `scalar_mul/G1` bench, and `G1/double_and_add`
+// shows the speedup over a naive implementation.
+fn bench_g1_scalar_mul_glv(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/scalar_mul");
+    let p = G1Projective::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+    assert_eq!(double_and_add(&p, &s), p * s);
+    group.bench_function("G1/glv", |b| b.iter(|| black_box(p) * black_box(s)));
+    group.bench_function("G1/double_and_add", |b| {
+        b.iter(|| double_and_add(black_box(&p), black_box(&s)))
+    });
+    group.finish()
+}
+
+/// Double-and-add over the bits of a small constant only, from its top set bit.
+fn mul_small<G: Group>(p: &G, k: u64) -> G {
+    (0..u64::BITS - k.leading_zeros())
+        .rev()
+        .fold(G::identity(), |acc, i| {
+            let acc = acc.double();
+            if (k >> i) & 1 == 1 {
+                acc + p
+            } else {
+                acc
+            }
+        })
+}
+
+// Multiplication by tiny constants, as in doubling, tripling or clearing the G1
+// cofactor `h_eff`. blst's multiplication takes the scalar's bit length, but
+// blstrs always passes the full 255 bits, so `scalar` costs as much as a
+// random scalar and `double_and_add` over the constant's own bits is the cost
+// to model composite operations with.
+fn bench_g1_mul_small(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/scalar_mul_small");
+    let p = G1Projective::random(&mut rng);
+    for (name, k) in [
+        ("2", 2),
+        ("3", 3),
+        ("16", 16),
+        ("h_eff", 0xd201000000010001),
+    ] {
+        let s = Scalar::from(k);
+        assert_eq!(mul_small(&p, k), p * s);
+        group.bench_function(format!("G1/{}/scalar", name), |b| {
+            b.iter(|| black_box(p) * black_box(s))
+        });
+        group.bench_function(format!("G1/{}/double_and_add", name), |b| {
+            b.iter(|| mul_small(black_box(&p), black_box(k)))
+        });
+    }
+    group.finish()
+}
+
+/// Precomputed table for fixed-base scalar multiplication using 8-bit windows:
+/// `table[i][j] = j * 256^i * base`. A multiplication then costs one mixed
+/// addition per non-zero scalar byte and no doublings.
+struct FixedBaseTable<G: PrimeCurve> {
+    table: Vec<Vec<G::Affine>>,
+}
+
+impl<G: PrimeCurve<Scalar = Scalar>> FixedBaseTable<G> {
+    const WINDOW: usize = 8;
+
+    fn new(base: G) -> Self {
+        let mut table = Vec::with_capacity(256 / Self::WINDOW);
+        let mut window_base = base;
+        for _ in 0..256 / Self::WINDOW {
+            let mut row = vec![G::identity(); 1 << Self::WINDOW];
+            for j in 1..row.len() {
+                row[j] = row[j - 1] + window_base;
+            }
+            let mut row_affine = vec![G::Affine::identity(); row.len()];
+            G::batch_normalize(&row, &mut row_affine);
+            table.push(row_affine);
+            for _ in 0..Self::WINDOW {
+                window_base = window_base.double();
+            }
+        }
+        FixedBaseTable { table }
+    }
+
+    fn mul(&self, scalar: &Scalar) -> G {
+        let mut acc = G::identity();
+        for (row, &digit) in self.table.iter().zip(scalar.to_bytes_le().iter()) {
+            if digit != 0 {
+                acc += &row[digit as usize];
+            }
+        }
+        acc
+    }
+}
+
+fn bench_g1_fixed_base(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/fixed_base");
+    // The table is built once: its cost is amortized over many multiplications
+    let table = FixedBaseTable::new(G1Projective::generator());
+    let rhs = Scalar::random(&mut rng);
+    for s in [rhs, -Scalar::ONE] {
+        assert_eq!(table.mul(&s), G1Projective::generator() * s);
+    }
+    group.bench_function(
+        format!("G1/w{}", FixedBaseTable::<G1Projective>::WINDOW),
+        |b| b.iter(|| table.mul(black_box(&rhs))),
+    );
+    group.finish()
+}
 
-    c.bench_function("bls12_381/mul_G1", |b| {
+// One random point times N scalars. `G1/table` builds a window table for the
+// point and pays for it inside the timed loop, so comparing it with the plain
+// per-scalar multiplication of `G1/naive` shows the N where the table pays off.
+fn bench_g1_batch_scalar_mul(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/batch_scalar_mul");
+    let p = G1Projective::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+    assert_eq!(FixedBaseTable::new(p).mul(&s), p * s);
+    for logsize in 0..=12 {
+        let size = 1 << logsize;
+        let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("G1/table", size), &size, |b, _| {
+            b.iter(|| -> Vec<_> {
+                let table = FixedBaseTable::new(black_box(p));
+                scalars.iter().map(|s| table.mul(s)).collect()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("G1/naive", size), &size, |b, _| {
+            b.iter(|| -> Vec<_> { scalars.iter().map(|s| black_box(p) * s).collect() })
+        });
+    }
+    group.finish()
+}
+
+// N independent sums of two affine points, with the affine results that MSMs
+// sum their buckets into. `G1/batched` shares one inversion among all the
+// slopes, `G1/independent` inverts every slope on its own.
+fn bench_g1_batch_affine_add(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/batch_affine_add");
+    for logsize in 0..=16 {
+        let size = 1 << logsize;
+        let pairs: Vec<_> = (0..size)
+            .map(|_| {
+                (
+                    G1Projective::random(&mut rng).to_affine(),
+                    G1Projective::random(&mut rng).to_affine(),
+                )
+            })
+            .collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("G1/batched", size), &size, |b, _| {
+            b.iter(|| affine::batch_add(black_box(&pairs)))
+        });
+        group.bench_with_input(BenchmarkId::new("G1/independent", size), &size, |b, _| {
+            b.iter(|| -> Vec<_> { pairs.iter().map(|(p, q)| affine::add(p, q)).collect() })
+        });
+    }
+    group.finish()
+}
+
+// Pippenger buckets accumulate affine inputs into projective sums, so this is
+// the addition that actually dominates MSM.
+fn bench_g1_mixed_add(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/mixed_add");
+    group.bench_function("G1", |b| {
         let lhs = G1Projective::random(&mut rng);
-        let rhs = Scalar::random(&mut rng);
-        b.iter(|| black_box(lhs) * black_box(rhs))
+        let rhs = G1Projective::random(&mut rng).to_affine();
+        b.iter(|| black_box(lhs) + black_box(&rhs))
     });
+    group.finish()
+}
 
-    c.bench_function("bls12_381/mul_G2", |b| {
+fn bench_g2_mixed_add(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/mixed_add");
+    group.bench_function("G2", |b| {
         let lhs = G2Projective::random(&mut rng);
-        let rhs = Scalar::random(&mut rng);
-        b.iter(|| black_box(lhs) * black_box(rhs))
+        let rhs = G2Projective::random(&mut rng).to_affine();
+        b.iter(|| black_box(lhs) + black_box(&rhs))
     });
+    group.finish()
+}
 
-    c.bench_function("bls12_381/mul_Gt", |b| {
-        let lhs = Gt::random(&mut rng);
-        let rhs = Scalar::random(&mut rng);
-        b.iter(|| black_box(lhs) * black_box(rhs))
+fn bench_g1_double(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/doubling");
+    group.bench_function("G1", |b| {
+        let p = G1Projective::random(&mut rng);
+        b.iter(|| black_box(p).double())
     });
+    group.finish()
 }
 
-fn bench_add(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
+fn bench_g2_double(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/doubling");
+    group.bench_function("G2", |b| {
+        let p = G2Projective::random(&mut rng);
+        b.iter(|| black_box(p).double())
+    });
+    group.finish()
+}
 
-    c.bench_function("bls12_381/add_ff", |b| {
-        let lhs = Scalar::random(&mut rng);
-        let rhs = Scalar::random(&mut rng);
+fn random_gt(rng: &mut impl rand::RngCore) -> Gt {
+    let a = G1Projective::random(&mut *rng).to_affine();
+    let b = G2Projective::random(&mut *rng).to_affine();
+    a.pairing_with(&b)
+}
+
+// Gt is written additively in blstrs: `+` is the Fp12 multiplication and
+// `* Scalar` is the exponentiation.
+fn bench_gt_mul(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/gt");
+    group.bench_function("mul", |b| {
+        let lhs = random_gt(&mut rng);
+        let rhs = random_gt(&mut rng);
         b.iter(|| black_box(lhs) + black_box(rhs))
     });
+    group.finish()
+}
 
-    c.bench_function("bls12_381/add_G1", |r| {
-        let a = G1Projective::random(&mut rng);
-        let b = G1Projective::random(&mut rng);
-        r.iter(|| a.add(b))
+fn bench_gt_exp(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/gt");
+    group.bench_function("exp", |b| {
+        let lhs = random_gt(&mut rng);
+        let rhs = Scalar::random(&mut rng);
+        b.iter(|| black_box(lhs) * black_box(rhs))
     });
+    group.finish()
+}
 
-    c.bench_function("bls12_381/add_G2", |r| {
-        let a = G2Projective::random(&mut rng);
-        let b = G2Projective::random(&mut rng);
-        r.iter(|| a.add(b))
-    });
+// blstrs has no multi-exponentiation in Gt, so this is the plain accumulation of
+// one exponentiation and one multiplication per term (Gt is written additively).
+fn bench_gt_msm(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/gt");
+    for logsize in 2..=10 {
+        let size = 1 << logsize;
+        let elements: Vec<_> = (0..size).map(|_| random_gt(&mut rng)).collect();
+        let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::new("msm", size), &size, |b, _| {
+            b.iter(|| {
+                elements
+                    .iter()
+                    .zip(&scalars)
+                    .fold(Gt::identity(), |acc, (g, s)| acc + g * s)
+            })
+        });
+    }
+    group.finish()
+}
 
-    c.bench_function("bls12_381/add_Gt", |r| {
-        let a = Gt::random(&mut rng);
-        let b = Gt::random(&mut rng);
-        r.iter(|| a.add(b))
-    });
+// For a handful of terms, as in a Groth16 verifier, Pippenger's bucket setup
+// dominates and a plain sum of scalar multiplications can win. Sweeping every
+// size up to 64 shows the crossover.
+fn bench_small_msm(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/small_msm");
+    for size in 1..=64 {
+        let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let naive = || -> G1Projective { points.iter().zip(&scalars).map(|(p, s)| p * s).sum() };
+        assert_eq!(msm::<Bls12_381>(&points, &scalars).unwrap(), naive());
+
+        group.bench_with_input(BenchmarkId::new("G1", size), &size, |b, _| {
+            b.iter(|| msm::<Bls12_381>(&points, &scalars).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("G1/naive", size), &size, |b, _| {
+            b.iter(&naive)
+        });
+    }
+    group.finish()
 }
 
-fn bench_msm(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
+// blstrs only exposes a projective-input MSM, so callers holding affine points
+// (e.g. from a trusted setup) pay for a conversion first. The conversion is
+// also measured on its own so its share of the total is visible.
+fn bench_msm_affine(c: &mut Criterion) {
+    let mut rng = config::rng();
 
-    let mut group = c.benchmark_group("bls12_381/msm");
-    for logsize in 1..=21 {
-        // Dynamically control sample size so that big MSMs don't bench eternally
-        if logsize > 20 {
-            group.sample_size(10);
-        }
+    let mut group = c.benchmark_group("bls12_381/msm_affine");
+    for logsize in config::msm_logsizes() {
+        group.sample_size(config::sample_size(logsize));
 
         let size = 1 << logsize;
         let vec_a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
         // G1 benchmarks
-        let vec_B_G1: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let vec_B_G1: Vec<_> = (0..size)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
         group.bench_with_input(BenchmarkId::new("G1", size), &size, |b, _| {
-            b.iter(|| G1Projective::multi_exp(&vec_B_G1, &vec_a));
+            b.iter(|| {
+                let vec_B: Vec<_> = vec_B_G1.iter().map(G1Projective::from).collect();
+                msm::<Bls12_381>(&vec_B, &vec_a).unwrap()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("G1/conversion", size), &size, |b, _| {
+            b.iter(|| -> Vec<_> { vec_B_G1.iter().map(G1Projective::from).collect() });
         });
 
         // G2 benchmarks
-        let vec_B_G2: Vec<_> = (0..size).map(|_| G2Projective::random(&mut rng)).collect();
+        let vec_B_G2: Vec<_> = (0..size)
+            .map(|_| G2Projective::random(&mut rng).to_affine())
+            .collect();
         group.bench_with_input(BenchmarkId::new("G2", size), &size, |b, _| {
-            b.iter(|| G2Projective::multi_exp(&vec_B_G2, &vec_a));
+            b.iter(|| {
+                let vec_B: Vec<_> = vec_B_G2.iter().map(G2Projective::from).collect();
+                msm_g2::<Bls12_381>(&vec_B, &vec_a).unwrap()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("G2/conversion", size), &size, |b, _| {
+            b.iter(|| -> Vec<_> { vec_B_G2.iter().map(G2Projective::from).collect() });
         });
     }
 
     group.finish()
 }
 
-fn bench_invert(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
-    c.bench_function("bls12_381/invert", |b| {
-        let a = Scalar::random(&mut rng);
-        b.iter(|| a.invert().unwrap());
+// A G2 MSM whose result feeds a pairing, as a verifier's aggregated G2 input
+// does: `G2/msm_prepare` also prepares the result, so its difference with
+// `G2/msm` is the marginal cost of the preparation.
+fn bench_g2_msm_then_prepare(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let logsizes = config::msm_logsizes();
+    let bases: Vec<_> = (0..1 << logsizes.end())
+        .map(|_| G2Projective::random(&mut rng))
+        .collect();
+
+    let mut group = c.benchmark_group("bls12_381/msm_then_prepare");
+    for logsize in logsizes {
+        group.sample_size(config::sample_size(logsize));
+        let size = 1 << logsize;
+        let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let bases = &bases[..size];
+        group.bench_with_input(BenchmarkId::new("G2/msm", size), &size, |b, _| {
+            b.iter(|| msm_g2::<Bls12_381>(bases, &scalars).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("G2/msm_prepare", size), &size, |b, _| {
+            b.iter(|| G2Prepared::from(msm_g2::<Bls12_381>(bases, &scalars).unwrap().to_affine()))
+        });
+    }
+    group.finish()
+}
+
+// Partitions the inputs into one chunk per rayon thread, runs `multi_exp` on
+// each chunk and sums the partial results. Note that blst already spreads a
+// single `multi_exp` over its own thread pool for large inputs.
+fn bench_msm_parallel(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let threads = rayon::current_num_threads();
+
+    let mut group = c.benchmark_group("bls12_381/msm_parallel");
+    for logsize in config::msm_logsizes() {
+        group.sample_size(config::sample_size(logsize));
+
+        let size = 1 << logsize;
+        let chunk = (size as usize).div_ceil(threads);
+        let vec_a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        // G1 benchmarks
+        let vec_B_G1: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let id = format!("G1/{}_threads", threads);
+        group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| {
+            b.iter(|| {
+                vec_B_G1
+                    .par_chunks(chunk)
+                    .zip(vec_a.par_chunks(chunk))
+                    .map(|(bases, scalars)| msm::<Bls12_381>(bases, scalars).unwrap())
+                    .reduce(G1Projective::identity, |acc, p| acc + p)
+            });
+        });
+
+        // G2 benchmarks
+        let vec_B_G2: Vec<_> = (0..size).map(|_| G2Projective::random(&mut rng)).collect();
+        let id = format!("G2/{}_threads", threads);
+        group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| {
+            b.iter(|| {
+                vec_B_G2
+                    .par_chunks(chunk)
+                    .zip(vec_a.par_chunks(chunk))
+                    .map(|(bases, scalars)| msm_g2::<Bls12_381>(bases, scalars).unwrap())
+                    .reduce(G2Projective::identity, |acc, p| acc + p)
+            });
+        });
+    }
+
+    group.finish()
+}
+
+// Splits the inputs into chunks of `config::msm_chunk` terms, one `multi_exp`
+// each, and adds up the partial results, as done where not all points fit in
+// memory at once. Only one chunk is live at a time, so the difference to the
+// plain `msm/G1` sweep is the overhead of bounding memory.
+fn bench_msm_chunked(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let chunk = config::msm_chunk();
+
+    let mut group = c.benchmark_group("bls12_381/msm_chunked");
+    for logsize in config::msm_logsizes() {
+        group.sample_size(config::sample_size(logsize));
+
+        let size = 1 << logsize;
+        let vec_a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let vec_B: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let chunked = || {
+            vec_B
+                .chunks(chunk)
+                .zip(vec_a.chunks(chunk))
+                .map(|(bases, scalars)| msm::<Bls12_381>(bases, scalars).unwrap())
+                .fold(G1Projective::identity(), |acc, p| acc + p)
+        };
+        assert_eq!(chunked(), msm::<Bls12_381>(&vec_B, &vec_a).unwrap());
+
+        let id = format!("G1/chunk_{}", chunk);
+        group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| b.iter(&chunked));
+    }
+
+    group.finish()
+}
+
+/// The `c`-bit window of `scalar` starting at bit `start`.
+fn scalar_window(limbs: &[u64; 4], start: usize, c: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut window = limbs[limb] >> shift;
+    if shift + c > 64 && limb + 1 < limbs.len() {
+        window |= limbs[limb + 1] << (64 - shift);
+    }
+    (window & ((1 << c) - 1)) as usize
+}
+
+/// First phase of a minimal Pippenger MSM: split every scalar into `c`-bit
+/// windows and accumulate each base into the bucket selected by its window.
+/// Returns one vector of `2^c - 1` buckets per window.
+fn pippenger_accumulate<G: PrimeCurve<Scalar = Scalar>>(
+    bases: &[G::Affine],
+    scalars: &[Scalar],
+    c: usize,
+) -> Vec<Vec<G>> {
+    let limbs: Vec<_> = scalars.iter().map(to_u64_limbs).collect();
+    let num_windows = (Scalar::NUM_BITS as usize).div_ceil(c);
+    (0..num_windows)
+        .map(|w| {
+            let mut buckets = vec![G::identity(); (1 << c) - 1];
+            for (base, limbs) in bases.iter().zip(limbs.iter()) {
+                let digit = scalar_window(limbs, w * c, c);
+                if digit != 0 {
+                    buckets[digit - 1] += base;
+                }
+            }
+            buckets
+        })
+        .collect()
+}
+
+/// Second phase of a minimal Pippenger MSM: sum each window's buckets with the
+/// running-sum trick and combine the windows with `c` doublings apiece.
+fn pippenger_reduce<G: PrimeCurve>(windows: &[Vec<G>], c: usize) -> G {
+    let mut acc = G::identity();
+    for buckets in windows.iter().rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+        let mut running = G::identity();
+        let mut sum = G::identity();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            sum += running;
+        }
+        acc += sum;
+    }
+    acc
+}
+
+fn pippenger<G: PrimeCurve<Scalar = Scalar>>(
+    bases: &[G::Affine],
+    scalars: &[Scalar],
+    c: usize,
+) -> G {
+    pippenger_reduce(&pippenger_accumulate::<G>(bases, scalars, c), c)
+}
+
+/// Pedersen commitment key with every generator's window shifts precomputed:
+/// `bases[i * WINDOWS + j] = 2^(8j) * G_i`. Since the generators are fixed, a
+/// commitment is then a single-window Pippenger over all shifted bases, with
+/// no doublings between windows.
+struct PedersenTable {
+    bases: Vec<G1Affine>,
+}
+
+impl PedersenTable {
+    const WINDOW: usize = 8;
+    const WINDOWS: usize = (Scalar::NUM_BITS as usize).div_ceil(Self::WINDOW);
+
+    fn new(generators: &[G1Projective]) -> Self {
+        let shifted: Vec<G1Projective> = generators
+            .iter()
+            .flat_map(|g| {
+                std::iter::successors(Some(*g), |p| {
+                    Some((0..Self::WINDOW).fold(*p, |p, _| p.double()))
+                })
+                .take(Self::WINDOWS)
+            })
+            .collect();
+        let mut bases = vec![G1Affine::identity(); shifted.len()];
+        G1Projective::batch_normalize(&shifted, &mut bases);
+        PedersenTable { bases }
+    }
+
+    fn commit(&self, scalars: &[Scalar]) -> G1Projective {
+        let mut buckets = vec![G1Projective::identity(); (1 << Self::WINDOW) - 1];
+        for (bases, s) in self.bases.chunks(Self::WINDOWS).zip(scalars) {
+            let limbs = to_u64_limbs(s);
+            for (j, base) in bases.iter().enumerate() {
+                let digit = scalar_window(&limbs, j * Self::WINDOW, Self::WINDOW);
+                if digit != 0 {
+                    buckets[digit - 1] += base;
+                }
+            }
+        }
+        pippenger_reduce(&[buckets], 0)
+    }
+}
+
+// The table variant holds 32 points per generator, so it stops at 2^14
+// generators (about 50 MB of affine points).
+const PEDERSEN_TABLE_MAX_LOGSIZE: usize = 14;
+
+// A Pedersen commitment to a vector is an MSM over a fixed set of generators.
+fn bench_pedersen_commit(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/pedersen_commit");
+    for logsize in config::msm_logsizes() {
+        group.sample_size(config::sample_size(logsize));
+
+        let size = 1 << logsize;
+        let generators: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let values: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::new("G1", size), &size, |b, _| {
+            b.iter(|| msm::<Bls12_381>(&generators, &values).unwrap())
+        });
+
+        if logsize <= PEDERSEN_TABLE_MAX_LOGSIZE {
+            let table = PedersenTable::new(&generators);
+            assert_eq!(
+                table.commit(&values),
+                msm::<Bls12_381>(&generators, &values).unwrap()
+            );
+            group.bench_with_input(BenchmarkId::new("G1/precomputed", size), &size, |b, _| {
+                b.iter(|| table.commit(&values))
+            });
+        }
+    }
+    group.finish()
+}
+
+// Provers that commit many times reuse the SRS as bases, so only the scalars
+// vary between MSMs: the tables are built once, outside the timing, and every
+// iteration commits to fresh scalars. The sweep stops where the tables do.
+fn bench_msm_fixed_bases(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/msm_fixed_bases");
+    for logsize in config::msm_logsizes().filter(|&l| l <= PEDERSEN_TABLE_MAX_LOGSIZE) {
+        group.sample_size(config::sample_size(logsize));
+
+        let size = 1 << logsize;
+        group.throughput(Throughput::Elements(size as u64));
+        let bases: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+        let table = PedersenTable::new(&bases);
+        let values: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        assert_eq!(
+            table.commit(&values),
+            msm::<Bls12_381>(&bases, &values).unwrap()
+        );
+        let mut scalars = || {
+            (0..size)
+                .map(|_| Scalar::random(&mut rng))
+                .collect::<Vec<_>>()
+        };
+        group.bench_with_input(BenchmarkId::new("G1/precomputed", size), &size, |b, _| {
+            b.iter_batched(&mut scalars, |s| table.commit(&s), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("G1/multi_exp", size), &size, |b, _| {
+            b.iter_batched(
+                &mut scalars,
+                |s| msm::<Bls12_381>(&bases, &s).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish()
+}
+
+// blst picks its Pippenger window internally, so the window tradeoff is
+// measured with the minimal Pippenger above.
+fn bench_msm_windows(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/msm_windows");
+    group.sample_size(10);
+    let size = 1 << 18;
+    let vec_a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+    let vec_B: Vec<_> = (0..size)
+        .map(|_| G1Projective::random(&mut rng).to_affine())
+        .collect();
+    // Checked on a prefix, which is enough to exercise every window and bucket
+    let check = 1 << 12;
+    let check_bases: Vec<_> = vec_B[..check].iter().map(G1Projective::from).collect();
+    let expected = msm::<Bls12_381>(&check_bases, &vec_a[..check]).unwrap();
+    for window in 8..=16 {
+        assert_eq!(
+            pippenger::<G1Projective>(&vec_B[..check], &vec_a[..check], window),
+            expected
+        );
+        group.bench_with_input(
+            BenchmarkId::new(format!("G1/c{}", window), size),
+            &window,
+            |b, &window| b.iter(|| pippenger::<G1Projective>(&vec_B, &vec_a, window)),
+        );
+    }
+
+    group.finish()
+}
+
+// The two phases of `pippenger`, timed apart for the MSM cost model: bucket
+// accumulation costs about one mixed addition per base and window, reduction
+// about two additions per bucket and window, independently of the size.
+fn bench_msm_phases(c: &mut Criterion) {
+    const WINDOW: usize = 13;
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/msm_phases");
+    group.sample_size(10);
+    let size = 1 << 18;
+    let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+    let bases: Vec<_> = (0..size)
+        .map(|_| G1Projective::random(&mut rng).to_affine())
+        .collect();
+    group.bench_with_input(BenchmarkId::new("G1/accumulate", size), &size, |b, _| {
+        b.iter(|| pippenger_accumulate::<G1Projective>(&bases, &scalars, WINDOW))
+    });
+    let windows = pippenger_accumulate::<G1Projective>(&bases, &scalars, WINDOW);
+    let projective: Vec<_> = bases.iter().map(G1Projective::from).collect();
+    assert_eq!(
+        pippenger_reduce(&windows, WINDOW),
+        msm::<Bls12_381>(&projective, &scalars).unwrap()
+    );
+    group.bench_with_input(BenchmarkId::new("G1/reduce", size), &size, |b, _| {
+        b.iter(|| pippenger_reduce(&windows, WINDOW))
     });
+    group.finish()
+}
+
+/// A random scalar below `2^bits`.
+fn random_scalar_bits(rng: &mut impl rand::RngCore, bits: usize) -> Scalar {
+    let mut bytes = Scalar::random(&mut *rng).to_bytes_le();
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        if 8 * i >= bits {
+            *byte = 0;
+        } else if 8 * i + 8 > bits {
+            *byte &= (1 << (bits - 8 * i)) - 1;
+        }
+    }
+    Scalar::from_bytes_le(&bytes).unwrap()
+}
+
+// Selectors, range-proof limbs and challenges are far shorter than the field.
+// blstrs always hands blst 255-bit scalars, but the all-zero windows of short
+// scalars fill no buckets, so the time still tracks the bit length.
+fn bench_msm_bits(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let size = 1 << 16;
+    let bases: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+
+    let mut group = c.benchmark_group("bls12_381/msm_bits");
+    for bits in [1, 16, 64, 128, 255] {
+        let scalars: Vec<_> = (0..size)
+            .map(|_| random_scalar_bits(&mut rng, bits))
+            .collect();
+        let id = format!("G1/{}_bits", bits);
+        group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| {
+            b.iter(|| msm::<Bls12_381>(&bases, &scalars).unwrap())
+        });
+    }
+    group.finish()
+}
+
+/// Bytes written between the iterations of the cold MSM, more than any
+/// last-level cache.
+const EVICTION_BYTES: usize = 256 << 20;
+
+// Back-to-back MSMs over the same bases find part of them still in cache,
+// unlike a prover's MSMs interleaved with other work. The cold variant evicts
+// the caches before every iteration, outside of the timing, by writing a
+// scratch buffer larger than them.
+fn bench_msm_cache(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let size = 1 << 18;
+    let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+    let bases: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+    let mut scratch = vec![0u8; EVICTION_BYTES];
+
+    let mut group = c.benchmark_group("bls12_381/msm_cache");
+    group.sample_size(10);
+    group.bench_with_input(BenchmarkId::new("G1/warm", size), &size, |b, _| {
+        b.iter(|| msm::<Bls12_381>(&bases, &scalars).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("G1/cold", size), &size, |b, _| {
+        b.iter_batched(
+            || {
+                scratch.iter_mut().for_each(|x| *x = x.wrapping_add(1));
+                black_box(&scratch);
+            },
+            |()| msm::<Bls12_381>(&bases, &scalars).unwrap(),
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish()
+}
+
+/// The number of distinct bases of the repeated case of `bench_msm_structured`.
+const REPEATED_BASES: usize = 16;
+
+// Whether the shape of the bases matters to blst's Pippenger: all distinct,
+// the powers `tau^i * G` of a trusted setup, or a few bases used over and
+// over. The scalars are random in all three cases.
+fn bench_msm_structured(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let size = 1 << 16;
+    let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+    let distinct: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+    let table = FixedBaseTable::new(G1Projective::random(&mut rng));
+    let tau = Scalar::random(&mut rng);
+    let powers: Vec<_> = std::iter::successors(Some(Scalar::ONE), |t| Some(t * tau))
+        .take(size)
+        .map(|t| table.mul(&t))
+        .collect();
+    let few: Vec<_> = (0..REPEATED_BASES)
+        .map(|_| G1Projective::random(&mut rng))
+        .collect();
+    let repeated: Vec<_> = few.iter().cycle().take(size).copied().collect();
+
+    let mut group = c.benchmark_group("bls12_381/msm_structured");
+    for (name, bases) in [
+        ("distinct", &distinct),
+        ("powers", &powers),
+        ("repeated", &repeated),
+    ] {
+        let id = format!("G1/{}", name);
+        group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| {
+            b.iter(|| msm::<Bls12_381>(bases, &scalars).unwrap())
+        });
+    }
+    group.finish()
+}
+
+// Parsing a canonical encoding only checks that it is below r, while a wide
+// (64-byte) input, such as a transcript challenge, needs a modular reduction.
+fn bench_scalar_from_bytes(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let bytes = Scalar::random(&mut rng).to_bytes_le();
+    c.bench_function("bls12_381/scalar_from_bytes", |b| {
+        b.iter(|| Scalar::from_bytes_le(black_box(&bytes)).unwrap())
+    });
+}
+
+fn bench_scalar_from_bytes_wide(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut bytes = [0u8; 64];
+    rng.fill(&mut bytes[..]);
+    c.bench_function("bls12_381/scalar_from_bytes_wide", |b| {
+        b.iter(|| from_bytes_wide(black_box(&bytes)))
+    });
+}
+
+// A verifier rederives every challenge by hashing the prover's messages: here
+// `n` messages, alternately points and scalars, are absorbed and one challenge
+// is squeezed out.
+fn bench_fiat_shamir_challenge(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/fiat_shamir_challenge");
+    for n in [1usize, 2, 4, 8, 16, 32, 64] {
+        let points: Vec<_> = (0..n.div_ceil(2))
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let scalars: Vec<_> = (0..n / 2).map(|_| Scalar::random(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| {
+                let mut transcript = Transcript::new(b"zkalc_bench");
+                for (i, p) in points.iter().enumerate() {
+                    transcript.absorb_point(p);
+                    if let Some(s) = scalars.get(i) {
+                        transcript.absorb_scalar(s);
+                    }
+                }
+                transcript.squeeze_challenge()
+            })
+        });
+    }
+    group.finish()
+}
+
+// The cost of drawing an input from the seeded RNG, to account for benches
+// that have to sample inside the timed loop. A random point encodes random
+// bytes to the curve: a hash to the field, a map and a cofactor clearing.
+fn bench_rand_scalar(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/rng");
+    group.bench_function("scalar", |b| b.iter(|| Scalar::random(&mut rng)));
+    group.finish()
 }
 
-fn bench_pairing(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
-    c.bench_function("bls12_381/pairing", |r| {
+fn bench_rand_g1(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/rng");
+    group.bench_function("G1", |b| b.iter(|| G1Projective::random(&mut rng)));
+    group.finish()
+}
+
+fn bench_rand_g2(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/rng");
+    group.bench_function("G2", |b| b.iter(|| G2Projective::random(&mut rng)));
+    group.finish()
+}
+
+// The conversions of interop code handing raw limbs to another library, split
+// by direction: into Montgomery form is a multiplication, out of it a
+// reduction.
+fn bench_scalar_to_montgomery(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let limbs = to_u64_limbs(&Scalar::random(&mut rng));
+    assert_eq!(from_montgomery(&to_montgomery(&limbs)), limbs);
+    c.bench_function("bls12_381/scalar_to_montgomery", |b| {
+        b.iter(|| to_montgomery(black_box(&limbs)))
+    });
+}
+
+fn bench_scalar_from_montgomery(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let s = Scalar::random(&mut rng);
+    c.bench_function("bls12_381/scalar_from_montgomery", |b| {
+        b.iter(|| from_montgomery(black_box(&s)))
+    });
+}
+
+// Element-wise operations on vectors, such as the Hadamard products of a
+// prover, stream their inputs from memory: per element, large sizes show the
+// memory bandwidth that single `add` and `mul` benchmarks hide.
+fn bench_vec_op(c: &mut Criterion, name: &str, op: impl Fn(&Scalar, &Scalar) -> Scalar) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group(format!("bls12_381/vec_{}", name));
+    for logsize in 1..=20 {
+        let size = 1 << logsize;
+        let x: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let y: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let mut out = vec![Scalar::ZERO; size];
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                for ((o, x), y) in out.iter_mut().zip(&x).zip(&y) {
+                    *o = op(x, y);
+                }
+            })
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_vec_add(c: &mut Criterion) {
+    bench_vec_op(c, "add", |x, y| x + y)
+}
+
+fn bench_vec_mul(c: &mut Criterion) {
+    bench_vec_op(c, "mul", |x, y| x * y)
+}
+
+// The powers of a challenge, as provers compute them to batch polynomials or
+// evaluate at a point.
+fn bench_powers_of_scalar(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/powers");
+    let x = Scalar::random(&mut rng);
+    for logsize in 1..=20 {
+        let size = 1 << logsize;
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| powers(black_box(x), size))
+        });
+    }
+    group.finish()
+}
+
+fn bench_batch_invert(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/batch_invert");
+    for logsize in 1..=20 {
+        let size = 1 << logsize;
+        let v: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        // Report per-element throughput to compare against a single `invert`
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter_batched_ref(|| v.clone(), |v| batch_invert(v), BatchSize::LargeInput)
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_to_affine<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/to_affine");
+    let p = G::random(&mut rng);
+    group.bench_function(name, |b| b.iter(|| black_box(p).to_affine()));
+    group.finish()
+}
+
+// Batch normalization shares a single inversion across all points. The
+// per-point conversion is measured over the same sizes so the crossover shows.
+fn bench_batch_normalize<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/batch_normalize");
+    for logsize in 0..=16 {
+        let size = 1 << logsize;
+        let points: Vec<_> = (0..size).map(|_| G::random(&mut rng)).collect();
+        let mut affine = vec![G::Affine::identity(); size];
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new(name, size), &size, |b, _| {
+            b.iter(|| G::batch_normalize(&points, &mut affine))
+        });
+        group.bench_with_input(
+            BenchmarkId::new(format!("{}/individual", name), size),
+            &size,
+            |b, _| b.iter(|| -> Vec<_> { points.iter().map(G::to_affine).collect() }),
+        );
+    }
+    group.finish()
+}
+
+fn bench_g1_to_affine(c: &mut Criterion) {
+    bench_to_affine::<G1Projective>(c, "G1")
+}
+
+fn bench_g2_to_affine(c: &mut Criterion) {
+    bench_to_affine::<G2Projective>(c, "G2")
+}
+
+fn bench_g1_batch_normalize(c: &mut Criterion) {
+    bench_batch_normalize::<G1Projective>(c, "G1")
+}
+
+fn bench_g2_batch_normalize(c: &mut Criterion) {
+    bench_batch_normalize::<G2Projective>(c, "G2")
+}
+
+// Projective equality cross-multiplies by the Z coordinates. `eq` compares two
+// distinct points; `eq/same` compares two representatives of one point, which
+// have different Z coordinates since `2p - p` is not normalized.
+fn bench_eq<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/eq");
+    let p = G::random(&mut rng);
+    let q = G::random(&mut rng);
+    group.bench_function(name, |b| b.iter(|| black_box(p) == black_box(q)));
+    group.finish()
+}
+
+fn bench_eq_same<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/eq");
+    let p = G::random(&mut rng);
+    let q = p.double() - p;
+    assert!(p == q);
+    group.bench_function(format!("{}/same", name), |b| {
+        b.iter(|| black_box(p) == black_box(q))
+    });
+    group.finish()
+}
+
+fn bench_is_identity<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/is_identity");
+    let p = G::random(&mut rng);
+    group.bench_function(name, |b| b.iter(|| bool::from(black_box(p).is_identity())));
+    group.finish()
+}
+
+fn bench_point_neg<G: PrimeCurve>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/group_misc");
+    let p = G::random(&mut rng);
+    group.bench_function(format!("{}/neg", name), |b| b.iter(|| -black_box(p)));
+    group.finish()
+}
+
+// A constant-time selection under a random choice, which is what GLV and
+// windowed multiplications do for every table lookup.
+fn bench_ct_select<G: PrimeCurve + ConditionallySelectable>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/group_misc");
+    let p = G::random(&mut rng);
+    let q = G::random(&mut rng);
+    let choice = Choice::from(rng.gen::<bool>() as u8);
+    group.bench_function(format!("{}/ct_select", name), |b| {
+        b.iter(|| G::conditional_select(&black_box(p), &black_box(q), black_box(choice)))
+    });
+    group.finish()
+}
+
+fn bench_g1_neg(c: &mut Criterion) {
+    bench_point_neg::<G1Projective>(c, "G1")
+}
+
+fn bench_g1_ct_select(c: &mut Criterion) {
+    bench_ct_select::<G1Projective>(c, "G1")
+}
+
+fn bench_g2_neg(c: &mut Criterion) {
+    bench_point_neg::<G2Projective>(c, "G2")
+}
+
+fn bench_g2_ct_select(c: &mut Criterion) {
+    bench_ct_select::<G2Projective>(c, "G2")
+}
+
+fn bench_g1_eq(c: &mut Criterion) {
+    bench_eq::<G1Projective>(c, "G1")
+}
+
+fn bench_g1_eq_same(c: &mut Criterion) {
+    bench_eq_same::<G1Projective>(c, "G1")
+}
+
+fn bench_g1_is_identity(c: &mut Criterion) {
+    bench_is_identity::<G1Projective>(c, "G1")
+}
+
+fn bench_g2_eq(c: &mut Criterion) {
+    bench_eq::<G2Projective>(c, "G2")
+}
+
+fn bench_g2_eq_same(c: &mut Criterion) {
+    bench_eq_same::<G2Projective>(c, "G2")
+}
+
+fn bench_g2_is_identity(c: &mut Criterion) {
+    bench_is_identity::<G2Projective>(c, "G2")
+}
+
+fn bench_miller_loop(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/miller_loop", |r| {
         let a = G1Projective::random(&mut rng).to_affine();
-        let b = G2Projective::random(&mut rng).to_affine();
-        r.iter(|| a.pairing_with(&b))
+        let b = G2Prepared::from(G2Projective::random(&mut rng).to_affine());
+        let v_ref = [(&a, &b)];
+        r.iter(|| Bls12::multi_miller_loop(black_box(&v_ref)))
     });
 }
 
+fn bench_final_exponentiation(c: &mut Criterion) {
+    let mut rng = config::rng();
+    c.bench_function("bls12_381/final_exponentiation", |r| {
+        let a = G1Projective::random(&mut rng).to_affine();
+        let b = G2Prepared::from(G2Projective::random(&mut rng).to_affine());
+        let f = Bls12::multi_miller_loop(&[(&a, &b)]);
+        r.iter(|| black_box(f).final_exponentiation())
+    });
+}
+
+// The two phases of `final_exponentiation`, on a random Fp12 standing in for
+// a Miller loop output, from blst's steps re-implemented in `final_exp`.
+fn bench_final_exp_easy(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/final_exp");
+    let f = Fp12::random(&mut rng);
+    group.bench_function("easy", |b| b.iter(|| easy_part(black_box(&f))));
+    group.finish()
+}
+
+fn bench_final_exp_hard(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/final_exp");
+    // The hard part relies on its input being in the cyclotomic subgroup
+    let f = easy_part(&Fp12::random(&mut rng));
+    group.bench_function("hard", |b| b.iter(|| hard_part(black_box(&f))));
+    group.finish()
+}
+
+// Verifiers pay this whenever a G2 input is not known ahead of time
+fn bench_g2_prepare(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/pairing");
+    let mut pool = InputPool::new(|| G2Projective::random(&mut rng).to_affine());
+    group.bench_function("g2_prepare", |b| {
+        b.iter(|| G2Prepared::from(*pool.next_input()))
+    });
+    group.finish()
+}
+
+// Verifiers check e(A, B) = e(C, D) as e(A, B) * e(C, -D) = 1: one two-term
+// Miller loop and a single final exponentiation instead of two full pairings.
+// The inputs are chosen so that the equation holds, which costs the same as a
+// failing check.
+fn bench_pairing_equality_check(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/pairing");
+    let s = Scalar::random(&mut rng);
+    let P = G1Projective::random(&mut rng);
+    let Q = G2Projective::random(&mut rng);
+    let A = P.to_affine();
+    let B = G2Prepared::from((Q * s).to_affine());
+    let C = (P * s).to_affine();
+    let neg_D = G2Prepared::from((-Q).to_affine());
+    group.bench_function("equality_check", |r| {
+        r.iter(|| {
+            Bls12::multi_miller_loop(&[(&A, &B), (&C, &neg_D)]).final_exponentiation()
+                == Gt::identity()
+        })
+    });
+    group.finish()
+}
+
 fn bench_multi_pairing(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     let mut group = c.benchmark_group("bls12_381/msm/Gt");
-    for d in 1..=10 {
+    for d in config::pairing_logsizes() {
+        group.sample_size(config::sample_size(d));
         let size = 1 << d;
         let mut v: Vec<(G1Affine, G2Prepared)> = Vec::new();
         for _ in 0..size {
@@ -121,10 +1287,7 @@ fn bench_multi_pairing(c: &mut Criterion) {
             v.push((g1, g2));
         }
 
-        let mut v_ref: Vec<(&G1Affine, &G2Prepared)> = Vec::new();
-        for i in 0..size {
-            v_ref.push((&v[i].0, &v[i].1));
-        }
+        let v_ref: Vec<(&G1Affine, &G2Prepared)> = v.iter().map(|(g1, g2)| (g1, g2)).collect();
 
         group.bench_with_input(BenchmarkId::from_parameter(size), &d, |b, _| {
             b.iter(|| Bls12::multi_miller_loop(&v_ref).final_exponentiation())
@@ -132,14 +1295,571 @@ fn bench_multi_pairing(c: &mut Criterion) {
     }
 }
 
+// blstrs has nothing to precompute for G1: the Miller loop takes G1 points in
+// affine form and only the line coefficients of G2 can be prepared. With one
+// G2 point fixed and prepared once, as a verifier with a fixed key has it,
+// this is the Miller loop cost per G1 input, each pairing computed on its own
+// and without the final exponentiation.
+fn bench_miller_loop_fixed_g2(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/miller_loop_fixed_g2");
+    let q = G2Prepared::from(G2Projective::random(&mut rng).to_affine());
+    for d in config::pairing_logsizes() {
+        group.sample_size(config::sample_size(d));
+        let size = 1 << d;
+        let g1: Vec<_> = (0..size)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &d, |b, _| {
+            b.iter(|| -> Vec<_> {
+                g1.iter()
+                    .map(|p| Bls12::multi_miller_loop(&[(p, &q)]))
+                    .collect()
+            })
+        });
+    }
+    group.finish()
+}
+
+// N independent equations e(A_i, B_i) == e(C_i, D_i), checked either one by one
+// or all at once as prod e(r_i A_i, B_i) e(r_i C_i, -D_i) == 1 for random r_i.
+// The G2 points are prepared outside the timed loop, but scaling by the r_i is
+// part of the batched check.
+fn bench_batch_pairing_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/batch_pairing_verify");
+    for d in config::pairing_logsizes() {
+        group.sample_size(config::sample_size(d));
+        let size = 1 << d;
+        let mut equations = Vec::with_capacity(size);
+        for _ in 0..size {
+            let s = Scalar::random(&mut rng);
+            let P = G1Projective::random(&mut rng);
+            let Q = G2Projective::random(&mut rng);
+            equations.push((P, (Q * s).to_affine(), P * s, Q.to_affine()));
+        }
+        let prepared: Vec<_> = equations
+            .iter()
+            .map(|(_, B, _, D)| (G2Prepared::from(*B), G2Prepared::from(-*D)))
+            .collect();
+        let coefficients: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("batched", size), &d, |b, _| {
+            b.iter(|| {
+                let mut terms = Vec::with_capacity(2 * size);
+                for ((A, _, C, _), r) in equations.iter().zip(&coefficients) {
+                    terms.push((*A * r).to_affine());
+                    terms.push((*C * r).to_affine());
+                }
+                let pairs: Vec<_> = terms
+                    .chunks(2)
+                    .zip(&prepared)
+                    .flat_map(|(g1, (B, neg_D))| [(&g1[0], B), (&g1[1], neg_D)])
+                    .collect();
+                Bls12::multi_miller_loop(&pairs).final_exponentiation() == Gt::identity()
+            })
+        });
+        let affine: Vec<_> = equations
+            .iter()
+            .map(|(A, B, C, D)| (A.to_affine(), *B, C.to_affine(), *D))
+            .collect();
+        group.bench_with_input(BenchmarkId::new("individual", size), &d, |b, _| {
+            b.iter(|| {
+                affine
+                    .iter()
+                    .all(|(A, B, C, D)| A.pairing_with(B) == C.pairing_with(D))
+            })
+        });
+    }
+    group.finish()
+}
+
+fn bench_serialize<A: PrimeCurveAffine + UncompressedEncoding>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/serialize");
+    let p = A::Curve::random(&mut rng).to_affine();
+    group.bench_function(format!("{}/compressed", name), |b| {
+        b.iter(|| black_box(p).to_bytes())
+    });
+    group.bench_function(format!("{}/uncompressed", name), |b| {
+        b.iter(|| black_box(p).to_uncompressed())
+    });
+    group.finish()
+}
+
+// Decompression is dominated by the subgroup check, so the checked and
+// unchecked parsing paths get distinct ids.
+fn bench_deserialize<A: PrimeCurveAffine + UncompressedEncoding>(c: &mut Criterion, name: &str) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/deserialize");
+    let p = A::Curve::random(&mut rng).to_affine();
+    let compressed = p.to_bytes();
+    let uncompressed = p.to_uncompressed();
+    group.bench_function(format!("{}/compressed/checked", name), |b| {
+        b.iter(|| A::from_bytes(black_box(&compressed)).unwrap())
+    });
+    group.bench_function(format!("{}/compressed/unchecked", name), |b| {
+        b.iter(|| A::from_bytes_unchecked(black_box(&compressed)).unwrap())
+    });
+    group.bench_function(format!("{}/uncompressed/checked", name), |b| {
+        b.iter(|| A::from_uncompressed(black_box(&uncompressed)).unwrap())
+    });
+    group.bench_function(format!("{}/uncompressed/unchecked", name), |b| {
+        b.iter(|| A::from_uncompressed_unchecked(black_box(&uncompressed)).unwrap())
+    });
+    group.finish()
+}
+
+fn bench_g1_serialize(c: &mut Criterion) {
+    bench_serialize::<G1Affine>(c, "G1")
+}
+
+fn bench_g1_deserialize(c: &mut Criterion) {
+    bench_deserialize::<G1Affine>(c, "G1")
+}
+
+fn bench_g2_serialize(c: &mut Criterion) {
+    bench_serialize::<G2Affine>(c, "G2")
+}
+
+fn bench_g2_deserialize(c: &mut Criterion) {
+    bench_deserialize::<G2Affine>(c, "G2")
+}
+
+// Loading a trusted setup: parsing N concatenated compressed G1 points into
+// affine points, with and without the subgroup check of every point.
+fn bench_srs_load(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/srs_load");
+    for logsize in 10..=16 {
+        if logsize > 14 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let srs: Vec<u8> = (0..size)
+            .flat_map(|_| G1Projective::random(&mut rng).to_affine().to_compressed())
+            .collect();
+        let load = |checked: bool| -> Vec<G1Affine> {
+            srs.chunks_exact(48)
+                .map(|bytes| {
+                    let bytes = bytes.try_into().unwrap();
+                    let p = if checked {
+                        G1Affine::from_compressed(bytes)
+                    } else {
+                        G1Affine::from_compressed_unchecked(bytes)
+                    };
+                    p.unwrap()
+                })
+                .collect()
+        };
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("G1/checked", size), &size, |b, _| {
+            b.iter(|| load(true))
+        });
+        group.bench_with_input(BenchmarkId::new("G1/unchecked", size), &size, |b, _| {
+            b.iter(|| load(false))
+        });
+    }
+    group.finish()
+}
+
+// A proof or an SRS chunk is a vector of points in one contiguous buffer,
+// which is reused across iterations here, as by a node serializing many
+// proofs. Compressed points are half the size but cost a square root to parse.
+fn bench_g1_vec_serialize(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/vec_serialize");
+    for logsize in 10..=16 {
+        let size = 1 << logsize;
+        let points: Vec<_> = (0..size)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let mut buf = Vec::with_capacity(size * 96);
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("G1/compressed", size), &size, |b, _| {
+            b.iter(|| {
+                buf.clear();
+                for p in &points {
+                    buf.extend_from_slice(&p.to_compressed());
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("G1/uncompressed", size), &size, |b, _| {
+            b.iter(|| {
+                buf.clear();
+                for p in &points {
+                    buf.extend_from_slice(&p.to_uncompressed());
+                }
+            })
+        });
+    }
+    group.finish()
+}
+
+/// Parses the concatenated `N`-byte encodings in `buf` into `out`.
+fn parse_points<const N: usize>(
+    buf: &[u8],
+    out: &mut Vec<G1Affine>,
+    parse: impl Fn(&[u8; N]) -> CtOption<G1Affine>,
+) {
+    out.clear();
+    out.extend(
+        buf.chunks_exact(N)
+            .map(|bytes| parse(bytes.try_into().unwrap()).unwrap()),
+    );
+}
+
+fn bench_g1_vec_deserialize(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/vec_deserialize");
+    for logsize in 10..=16 {
+        if logsize > 14 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let points: Vec<_> = (0..size)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let compressed: Vec<u8> = points.iter().flat_map(|p| p.to_compressed()).collect();
+        let uncompressed: Vec<u8> = points.iter().flat_map(|p| p.to_uncompressed()).collect();
+        let mut out = Vec::with_capacity(size);
+
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("G1/compressed/checked", size),
+            &size,
+            |b, _| b.iter(|| parse_points(&compressed, &mut out, G1Affine::from_compressed)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("G1/compressed/unchecked", size),
+            &size,
+            |b, _| {
+                b.iter(|| parse_points(&compressed, &mut out, G1Affine::from_compressed_unchecked))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("G1/uncompressed/checked", size),
+            &size,
+            |b, _| b.iter(|| parse_points(&uncompressed, &mut out, G1Affine::from_uncompressed)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("G1/uncompressed/unchecked", size),
+            &size,
+            |b, _| {
+                b.iter(|| {
+                    parse_points(
+                        &uncompressed,
+                        &mut out,
+                        G1Affine::from_uncompressed_unchecked,
+                    )
+                })
+            },
+        );
+        assert_eq!(out, points);
+    }
+    group.finish()
+}
+
+// G1Projective::random() hashes to the curve, so these points are genuine
+// subgroup elements and the check runs to completion.
+fn bench_g1_subgroup_check(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/subgroup_check");
+    group.bench_function("G1", |b| {
+        let p = G1Projective::random(&mut rng).to_affine();
+        b.iter(|| bool::from(black_box(p).is_torsion_free()))
+    });
+    group.finish()
+}
+
+fn bench_g2_subgroup_check(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/subgroup_check");
+    group.bench_function("G2", |b| {
+        let p = G2Projective::random(&mut rng).to_affine();
+        b.iter(|| bool::from(black_box(p).is_torsion_free()))
+    });
+    group.finish()
+}
+
+// The points are on the curve but almost surely outside the subgroup, as after
+// mapping to the curve or importing an unchecked point.
+fn bench_g1_clear_cofactor(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/clear_cofactor");
+    let p = random_g1_point(&mut rng);
+    group.bench_function("G1", |b| b.iter(|| clear_cofactor_g1(black_box(&p))));
+    group.finish()
+}
+
+fn bench_g2_clear_cofactor(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/clear_cofactor");
+    let p = random_g2_point(&mut rng);
+    group.bench_function("G2", |b| b.iter(|| clear_cofactor_g2(black_box(&p))));
+    group.finish()
+}
+
+// Domain-separation tags of the BLS signature ciphersuites (draft-irtf-cfrg-bls-signature).
+const G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+const G2_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+// The nonuniform `encode_to_curve` suites, which blstrs does not expose
+const G1_NU_DST: &[u8] = b"BLS12381G1_XMD:SHA-256_SSWU_NU_";
+const G2_NU_DST: &[u8] = b"BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+// hash_to_curve is the full random-oracle encoding: hash to two field
+// elements, map both to the curve, add them and clear the cofactor, so the
+// result lands in the subgroup. The `_NU_` ids are the nonuniform
+// encode_to_curve, which hashes to and maps a single field element.
+fn bench_hash_to_g1(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/hash_to_curve");
+    let msg: [u8; 32] = rng.gen();
+    group.bench_function(format!("G1/{}", String::from_utf8_lossy(G1_DST)), |b| {
+        b.iter(|| G1Projective::hash_to_curve(black_box(&msg), G1_DST, &[]))
+    });
+    group.bench_function(format!("G1/{}", String::from_utf8_lossy(G1_NU_DST)), |b| {
+        b.iter(|| encode_to_g1(black_box(&msg), G1_NU_DST))
+    });
+    group.finish()
+}
+
+fn bench_hash_to_g2(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/hash_to_curve");
+    let msg: [u8; 32] = rng.gen();
+    group.bench_function(format!("G2/{}", String::from_utf8_lossy(G2_DST)), |b| {
+        b.iter(|| G2Projective::hash_to_curve(black_box(&msg), G2_DST, &[]))
+    });
+    group.bench_function(format!("G2/{}", String::from_utf8_lossy(G2_NU_DST)), |b| {
+        b.iter(|| encode_to_g2(black_box(&msg), G2_NU_DST))
+    });
+    group.finish()
+}
+
+// The message expansion and field reduction that hash_to_curve starts with,
+// parameterized by the number of field elements produced.
+fn bench_hash_to_field(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/hash_to_field");
+    let msg: [u8; 32] = rng.gen();
+    for count in [1, 2] {
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, &count| {
+            b.iter(|| hash_to_field(black_box(&msg), G1_DST, count))
+        });
+    }
+    group.finish()
+}
+
+// BLS signatures with public keys in G1 and signatures in G2: a signature on
+// `msg` verifies if e(pk, H(msg)) = e(g1, sig), checked as a two-term
+// multi-Miller loop against -g1 followed by a single final exponentiation.
+fn bls_verify(pk: &G1Affine, msg: &[u8], sig: &G2Affine) -> bool {
+    let h = G2Prepared::from(G2Projective::hash_to_curve(msg, G2_DST, &[]).to_affine());
+    let sig = G2Prepared::from(*sig);
+    let neg_g1 = -G1Affine::generator();
+    Bls12::multi_miller_loop(&[(pk, &h), (&neg_g1, &sig)])
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
+fn bls_keygen(rng: &mut impl rand::RngCore) -> (Scalar, G1Affine) {
+    let sk = Scalar::random(rng);
+    (sk, (G1Projective::generator() * sk).to_affine())
+}
+
+fn bls_sign(sk: &Scalar, msg: &[u8]) -> G2Projective {
+    G2Projective::hash_to_curve(msg, G2_DST, &[]) * sk
+}
+
+fn bench_bls_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/bls");
+    let msg: [u8; 32] = rng.gen();
+    let (sk, pk) = bls_keygen(&mut rng);
+    let sig = bls_sign(&sk, &msg).to_affine();
+    assert!(bls_verify(&pk, &msg, &sig));
+
+    group.bench_function("verify", |b| {
+        b.iter(|| bls_verify(black_box(&pk), black_box(&msg), black_box(&sig)))
+    });
+    group.finish()
+}
+
+// N signers sign the same message, so the signatures and public keys
+// aggregate to a single pair and verification stays at two Miller loops plus
+// the N public key additions.
+fn bench_bls_aggregate_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/bls/aggregate_verify");
+    let msg: [u8; 32] = rng.gen();
+    for d in 1..=10 {
+        let size = 1 << d;
+        let (sks, pks): (Vec<_>, Vec<_>) = (0..size).map(|_| bls_keygen(&mut rng)).unzip();
+        let sig = sks
+            .iter()
+            .map(|sk| bls_sign(sk, &msg))
+            .sum::<G2Projective>()
+            .to_affine();
+
+        let aggregate_verify = |pks: &[G1Affine], sig: &G2Affine| {
+            let pk = pks.iter().map(G1Projective::from).sum::<G1Projective>();
+            bls_verify(&pk.to_affine(), &msg, sig)
+        };
+        assert!(aggregate_verify(&pks, &sig));
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &d, |b, _| {
+            b.iter(|| aggregate_verify(black_box(&pks), black_box(&sig)))
+        });
+    }
+    group.finish()
+}
+
+// N signers sign N distinct messages, so nothing aggregates on the public key
+// side: verification hashes every message and checks
+// e(-g1, sig) * prod e(pk_i, H(msg_i)) = 1 as one multi-Miller loop of N + 1
+// pairs and a single final exponentiation.
+fn bls_aggregate_verify_distinct(pks: &[G1Affine], msgs: &[[u8; 32]], sig: &G2Affine) -> bool {
+    let hashes: Vec<_> = msgs
+        .iter()
+        .map(|msg| G2Prepared::from(G2Projective::hash_to_curve(msg, G2_DST, &[]).to_affine()))
+        .collect();
+    let sig = G2Prepared::from(*sig);
+    let neg_g1 = -G1Affine::generator();
+    let mut pairs: Vec<_> = pks.iter().zip(&hashes).collect();
+    pairs.push((&neg_g1, &sig));
+    Bls12::multi_miller_loop(&pairs)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
+fn bench_bls_aggregate_verify_distinct(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/bls/aggregate_verify_distinct");
+    for d in 1..=10 {
+        // Every signer adds a hash to G2 and a Miller loop
+        if d > 6 {
+            group.sample_size(10);
+        }
+        let size = 1 << d;
+        let (sks, pks): (Vec<_>, Vec<_>) = (0..size).map(|_| bls_keygen(&mut rng)).unzip();
+        let msgs: Vec<[u8; 32]> = (0..size).map(|_| rng.gen()).collect();
+        let sig = sks
+            .iter()
+            .zip(&msgs)
+            .map(|(sk, msg)| bls_sign(sk, msg))
+            .sum::<G2Projective>()
+            .to_affine();
+        assert!(bls_aggregate_verify_distinct(&pks, &msgs, &sig));
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &d, |b, _| {
+            b.iter(|| {
+                bls_aggregate_verify_distinct(black_box(&pks), black_box(&msgs), black_box(&sig))
+            })
+        });
+    }
+    group.finish()
+}
+
+fn bench_shared(c: &mut Criterion) {
+    bench_field_ops::<Bls12_381>(c);
+    bench_group_ops::<Bls12_381>(c);
+    bench_pairing_ops::<Bls12_381>(c);
+}
+
 criterion_group!(
     blstrs_benchmarks,
-    bench_mul,
-    bench_add,
-    bench_msm,
-    bench_invert,
-    bench_pairing,
+    bench_shared,
+    bench_rand_scalar,
+    bench_rand_g1,
+    bench_rand_g2,
+    bench_square,
+    bench_sub,
+    bench_neg,
+    bench_pow,
+    bench_invert_fermat,
+    bench_sqrt,
+    bench_is_square,
+    bench_fp2,
+    bench_fp12,
+    bench_g1_scalar_mul_glv,
+    bench_g1_mul_small,
+    bench_g1_fixed_base,
+    bench_g1_batch_scalar_mul,
+    bench_g1_mixed_add,
+    bench_g2_mixed_add,
+    bench_g1_batch_affine_add,
+    bench_g1_double,
+    bench_g2_double,
+    bench_gt_mul,
+    bench_gt_exp,
+    bench_gt_msm,
+    bench_small_msm,
+    bench_msm_affine,
+    bench_g2_msm_then_prepare,
+    bench_msm_parallel,
+    bench_msm_chunked,
+    bench_msm_windows,
+    bench_msm_phases,
+    bench_msm_bits,
+    bench_msm_structured,
+    bench_msm_cache,
+    bench_pedersen_commit,
+    bench_msm_fixed_bases,
+    bench_vec_add,
+    bench_vec_mul,
+    bench_powers_of_scalar,
+    bench_batch_invert,
+    bench_scalar_from_bytes,
+    bench_scalar_from_bytes_wide,
+    bench_fiat_shamir_challenge,
+    bench_scalar_to_montgomery,
+    bench_scalar_from_montgomery,
+    bench_g1_to_affine,
+    bench_g2_to_affine,
+    bench_g1_batch_normalize,
+    bench_g2_batch_normalize,
+    bench_g1_eq,
+    bench_g1_eq_same,
+    bench_g1_is_identity,
+    bench_g2_eq,
+    bench_g2_eq_same,
+    bench_g2_is_identity,
+    bench_g1_neg,
+    bench_g1_ct_select,
+    bench_g2_neg,
+    bench_g2_ct_select,
+    bench_miller_loop,
+    bench_final_exponentiation,
+    bench_final_exp_easy,
+    bench_final_exp_hard,
+    bench_g2_prepare,
+    bench_pairing_equality_check,
     bench_multi_pairing,
+    bench_miller_loop_fixed_g2,
+    bench_batch_pairing_verify,
+    bench_g1_serialize,
+    bench_g1_deserialize,
+    bench_g2_serialize,
+    bench_g2_deserialize,
+    bench_srs_load,
+    bench_g1_vec_serialize,
+    bench_g1_vec_deserialize,
+    bench_g1_subgroup_check,
+    bench_g2_subgroup_check,
+    bench_g1_clear_cofactor,
+    bench_g2_clear_cofactor,
+    bench_hash_to_g1,
+    bench_hash_to_g2,
+    bench_hash_to_field,
+    bench_bls_verify,
+    bench_bls_aggregate_verify,
+    bench_bls_aggregate_verify_distinct,
 );
 
 criterion_main!(blstrs_benchmarks);
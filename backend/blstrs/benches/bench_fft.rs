@@ -0,0 +1,283 @@
+use blstrs::Scalar;
+use blstrs_benchmarks::fft::{twiddle_factors, Domain};
+use blstrs_benchmarks::poly;
+use criterion::*;
+use ff::{Field, PrimeField};
+use std::ops::RangeInclusive;
+use zkalc_common::config;
+
+// Also the sizes of the interpolation sweeps, up to `config::quadratic_logsizes`
+const FFT_LOGSIZES: RangeInclusive<usize> = 10..=20;
+
+fn bench_fft(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/fft");
+    for logsize in FFT_LOGSIZES {
+        // Roots of unity are precomputed once per domain
+        let domain = Domain::new(logsize as u32);
+        let size = domain.size();
+        let mut coeffs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| domain.fft(&mut coeffs))
+        });
+        group.bench_with_input(BenchmarkId::new("inverse", size), &size, |b, _| {
+            b.iter(|| domain.ifft(&mut coeffs))
+        });
+    }
+
+    group.finish()
+}
+
+// The coset shift costs two multiplications per element on top of the FFT.
+fn bench_coset_fft(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/fft");
+    for logsize in 10..=20 {
+        let domain = Domain::new(logsize);
+        let size = domain.size();
+        let mut coeffs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("coset", size), &size, |b, _| {
+            b.iter(|| domain.coset_fft(&mut coeffs))
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_coset_ifft(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/fft");
+    for logsize in 10..=20 {
+        let domain = Domain::new(logsize);
+        let size = domain.size();
+        let mut evals: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("coset_inverse", size), &size, |b, _| {
+            b.iter(|| domain.coset_ifft(&mut evals))
+        });
+    }
+
+    group.finish()
+}
+
+// Computing the twiddle factors is the part of `Domain::new` that one-shot
+// transforms cannot amortize: one multiplication per factor.
+fn bench_fft_twiddle_precompute(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12_381/fft");
+    for logsize in 10..=20 {
+        let size = 1 << logsize;
+        group.bench_with_input(
+            BenchmarkId::new("twiddle", size),
+            &logsize,
+            |b, &logsize| b.iter(|| twiddle_factors(logsize)),
+        );
+    }
+    group.finish()
+}
+
+// One FRI folding round halves the codeword: two multiplications per pair of
+// evaluations, with the inverse roots taken from the FFT domain.
+fn bench_fri_fold(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/fri_fold");
+    for logsize in 10..=20 {
+        let domain = Domain::new(logsize);
+        let size = domain.size();
+        let evals: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let beta = Scalar::random(&mut rng);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| domain.fri_fold(&evals, beta))
+        });
+    }
+
+    group.finish()
+}
+
+// Building a domain computes its roots of unity and their inverses, one
+// multiplication each.
+fn bench_domain_setup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12_381/domain_setup");
+    for logsize in 10..=20 {
+        let size = 1 << logsize;
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &logsize,
+            |b, &logsize| b.iter(|| Domain::new(logsize)),
+        );
+    }
+    group.finish()
+}
+
+fn bench_vanishing_eval(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/vanishing_eval");
+    for logsize in 10..=20 {
+        let domain = Domain::new(logsize);
+        let size = domain.size();
+        let x = Scalar::random(&mut rng);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| domain.evaluate_vanishing(black_box(x)))
+        });
+    }
+    group.finish()
+}
+
+/// A PLONK-style quotient `t = c / Z_H` on the extended domain `ext`, given the
+/// constraint polynomial's evaluations over the coset `g * ext`: on the coset,
+/// `Z_H(x) = x^n - 1` takes only `|ext| / n` distinct values, so their
+/// inverses are computed once and the division is one multiplication per
+/// evaluation, followed by an inverse coset FFT to the coefficients of `t`.
+fn quotient(domain: &Domain, ext: &Domain, evals: &mut [Scalar]) {
+    let g = Scalar::MULTIPLICATIVE_GENERATOR;
+    let z_inv: Vec<_> = (0..ext.size() / domain.size())
+        .map(|i| {
+            let x = g * ext.omega().pow_vartime([i as u64]);
+            domain.evaluate_vanishing(x).invert().unwrap()
+        })
+        .collect();
+    for (e, z_inv) in evals.iter_mut().zip(z_inv.iter().cycle()) {
+        *e *= z_inv;
+    }
+    ext.coset_ifft(evals);
+}
+
+// The constraints of a PLONK gate of degree 4 define a polynomial of degree
+// about 4n on a circuit of n gates, so it is evaluated on a coset of a domain
+// four times as large. Computing those evaluations is not included, only the
+// division by the vanishing polynomial and the interpolation of the quotient.
+fn bench_quotient_compute(c: &mut Criterion) {
+    let mut rng = config::rng();
+    // On c = q * Z_H, the quotient recovers q
+    let (domain, ext) = (Domain::new(4), Domain::new(6));
+    let q: Vec<_> = (0..3 * domain.size())
+        .map(|_| Scalar::random(&mut rng))
+        .collect();
+    let mut c_coeffs = vec![Scalar::ZERO; ext.size()];
+    for (i, x) in q.iter().enumerate() {
+        c_coeffs[i + domain.size()] += x;
+        c_coeffs[i] -= x;
+    }
+    ext.coset_fft(&mut c_coeffs);
+    quotient(&domain, &ext, &mut c_coeffs);
+    assert_eq!(&c_coeffs[..q.len()], &q[..]);
+    assert!(c_coeffs[q.len()..].iter().all(|x| bool::from(x.is_zero())));
+
+    let mut group = c.benchmark_group("bls12_381/quotient");
+    for logsize in 10..=18 {
+        if logsize > 16 {
+            group.sample_size(10);
+        }
+        let (domain, ext) = (Domain::new(logsize), Domain::new(logsize + 2));
+        let size = domain.size();
+        let evals: Vec<_> = (0..ext.size()).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter_batched_ref(
+                || evals.clone(),
+                |evals| quotient(&domain, &ext, evals),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_poly_mul(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/poly_mul");
+    for logsize in 10..=19 {
+        let size = 1 << logsize;
+        // The product of two size-n polynomials needs a domain of size 2n
+        let domain = Domain::new(logsize + 1);
+        let a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let b: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |r, _| {
+            r.iter(|| poly::mul(&domain, &a, &b))
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_poly_eval(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/poly_eval");
+    for logsize in 10..=20 {
+        let size = 1 << logsize;
+        let coeffs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let x = Scalar::random(&mut rng);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| poly::evaluate(&coeffs, black_box(&x)))
+        });
+    }
+
+    group.finish()
+}
+
+// Interpolating over arbitrary nodes is quadratic, so this sweep follows the
+// FFT one only up to `ZKALC_QUADRATIC_MAX`.
+fn bench_interpolate(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/interpolate");
+    group.sample_size(10);
+    for logsize in config::quadratic_logsizes(FFT_LOGSIZES) {
+        let size = 1 << logsize;
+        let xs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let ys: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| poly::interpolate(&xs, &ys))
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_barycentric_weights(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/barycentric_weights");
+    group.sample_size(10);
+    for logsize in config::quadratic_logsizes(FFT_LOGSIZES) {
+        let size = 1 << logsize;
+        let xs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| poly::barycentric_weights(&xs))
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(
+    fft_benchmarks,
+    bench_fft,
+    bench_coset_fft,
+    bench_coset_ifft,
+    bench_fft_twiddle_precompute,
+    bench_fri_fold,
+    bench_domain_setup,
+    bench_vanishing_eval,
+    bench_quotient_compute,
+    bench_poly_mul,
+    bench_poly_eval,
+    bench_interpolate,
+    bench_barycentric_weights,
+);
+
+criterion_main!(fft_benchmarks);
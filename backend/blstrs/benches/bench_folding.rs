@@ -0,0 +1,60 @@
+//! One Nova folding step: the commitments to the incoming witness and the
+//! cross term, the folding challenge and the folded commitments.
+
+use blstrs::{G1Projective, Scalar};
+use blstrs_benchmarks::curve::{cheap_points, Bls12_381};
+use blstrs_benchmarks::field::from_bytes_wide;
+use blstrs_benchmarks::merkle::{MerkleHash, PoseidonHash};
+use criterion::*;
+use ff::Field;
+use group::{Curve, Group};
+use zkalc_common::config;
+use zkalc_common::msm::msm;
+
+/// Maps a commitment to a scalar, to absorb it into the transcript.
+fn absorb_point(p: &G1Projective) -> Scalar {
+    let mut wide = [0u8; 64];
+    wide[..48].copy_from_slice(&p.to_affine().to_compressed());
+    from_bytes_wide(&wide)
+}
+
+// A Nova folding step commits to the incoming instance's witness and to the
+// cross term of the two instances, both MSMs over witness-sized vectors,
+// hashes the commitments with Poseidon into the folding challenge and folds
+// the running instance's commitments with it. Computing the cross term and
+// folding the witness vectors themselves are not included.
+fn bench_folding_step(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let max_logsize = 20;
+    let bases: Vec<G1Projective> = cheap_points(1 << max_logsize, &mut rng);
+    let poseidon = PoseidonHash::default();
+    let (running_w, running_e) = (
+        G1Projective::random(&mut rng),
+        G1Projective::random(&mut rng),
+    );
+
+    let mut group = c.benchmark_group("bls12_381/folding_step");
+    for logsize in 10..=max_logsize {
+        if logsize > 16 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let w: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let t: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let bases = &bases[..size];
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                let comm_w = msm::<Bls12_381>(bases, &w).unwrap();
+                let comm_t = msm::<Bls12_381>(bases, &t).unwrap();
+                let r = poseidon.hash_pair(&absorb_point(&comm_w), &absorb_point(&comm_t));
+                (running_w + comm_w * r, running_e + comm_t * r)
+            })
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(folding_benchmarks, bench_folding_step,);
+criterion_main!(folding_benchmarks);
@@ -0,0 +1,238 @@
+//! Groth16: the MSMs that dominate proving, and verifying one proof or a
+//! batch of proofs against the same key.
+
+use blstrs::{Bls12, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt, Scalar};
+use blstrs_benchmarks::curve::{cheap_points, Bls12_381};
+use criterion::*;
+use ff::Field;
+use group::{Curve, Group};
+use pairing_lib::{Engine, MillerLoopResult, MultiMillerLoop};
+use zkalc_common::config;
+use zkalc_common::msm::{msm, msm_g2};
+
+// Groth16 proving is dominated by five MSMs over the witness `w` and the
+// quotient coefficients `h`. For a circuit of n constraints over about as many
+// variables they are [A] in G1, [B] in G2 and in G1, and the H and L queries
+// of [C] in G1. Only these are measured: witness generation, constraint
+// evaluation and the FFTs computing `h` are not included. The G1 queries share
+// bases, sliced from one vector like the KZG SRS, as that does not change
+// their cost.
+fn bench_groth16_prover_msms(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let max_logsize = 20;
+    let g1: Vec<G1Projective> = cheap_points(1 << max_logsize, &mut rng);
+    let g2: Vec<G2Projective> = cheap_points(1 << max_logsize, &mut rng);
+
+    let mut group = c.benchmark_group("bls12_381/groth16_prover_msms");
+    for logsize in 10..=max_logsize {
+        if logsize > 16 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let w: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let h: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let (g1, g2) = (&g1[..size], &g2[..size]);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| {
+                let a = msm::<Bls12_381>(g1, &w).unwrap();
+                let b_g2 = msm_g2::<Bls12_381>(g2, &w).unwrap();
+                let b_g1 = msm::<Bls12_381>(g1, &w).unwrap();
+                let c_g1 = msm::<Bls12_381>(g1, &h).unwrap() + msm::<Bls12_381>(g1, &w).unwrap();
+                (a, b_g2, b_g1, c_g1)
+            })
+        });
+    }
+
+    group.finish()
+}
+
+// A Groth16 verifying key for `n` public inputs, with e(alpha, beta) computed
+// ahead of time and gamma and delta prepared, as verifiers store them.
+struct VerifyingKey {
+    alpha_beta: Gt,
+    gamma: G2Prepared,
+    delta: G2Prepared,
+    ic: Vec<G1Projective>,
+}
+
+#[derive(Clone)]
+struct Proof {
+    a: G1Affine,
+    b: G2Affine,
+    c: G1Affine,
+}
+
+// Checks e(A, B) = e(alpha, beta) e(L, gamma) e(C, delta), where
+// L = ic_0 + sum x_i ic_{i + 1} is the public-input MSM, as one multi-Miller
+// loop of three pairs and a single final exponentiation.
+fn groth16_verify(vk: &VerifyingKey, proof: &Proof, inputs: &[Scalar]) -> bool {
+    let l = vk.ic[0] + msm::<Bls12_381>(&vk.ic[1..], inputs).unwrap();
+    let b = G2Prepared::from(proof.b);
+    let (neg_l, neg_c) = ((-l).to_affine(), -proof.c);
+    Bls12::multi_miller_loop(&[(&proof.a, &b), (&neg_l, &vk.gamma), (&neg_c, &vk.delta)])
+        .final_exponentiation()
+        == vk.alpha_beta
+}
+
+// The discrete logarithms of a verifying key, from which proofs that pass its
+// check are made up without a circuit or a prover.
+struct Trapdoor {
+    alpha: Scalar,
+    beta: Scalar,
+    gamma: Scalar,
+    delta: Scalar,
+    ic: Vec<Scalar>,
+}
+
+fn groth16_keys(rng: &mut impl rand::RngCore, n: usize) -> (VerifyingKey, Trapdoor) {
+    let [alpha, beta, gamma, delta] = [(); 4].map(|_| Scalar::random(&mut *rng));
+    let ic: Vec<_> = (0..=n).map(|_| Scalar::random(&mut *rng)).collect();
+
+    let (g1, g2) = (G1Projective::generator(), G2Projective::generator());
+    let vk = VerifyingKey {
+        alpha_beta: Bls12::pairing(&(g1 * alpha).to_affine(), &(g2 * beta).to_affine()),
+        gamma: G2Prepared::from((g2 * gamma).to_affine()),
+        delta: G2Prepared::from((g2 * delta).to_affine()),
+        ic: ic.iter().map(|s| g1 * s).collect(),
+    };
+    let trapdoor = Trapdoor {
+        alpha,
+        beta,
+        gamma,
+        delta,
+        ic,
+    };
+    (vk, trapdoor)
+}
+
+// A proof for random public inputs, with random A and B and the verification
+// equation solved for C.
+fn groth16_proof(rng: &mut impl rand::RngCore, td: &Trapdoor) -> (Proof, Vec<Scalar>) {
+    let [a, b] = [(); 2].map(|_| Scalar::random(&mut *rng));
+    let inputs: Vec<_> = (1..td.ic.len())
+        .map(|_| Scalar::random(&mut *rng))
+        .collect();
+    let l = td.ic[0]
+        + td.ic[1..]
+            .iter()
+            .zip(&inputs)
+            .map(|(s, x)| s * x)
+            .sum::<Scalar>();
+    let c = (a * b - td.alpha * td.beta - l * td.gamma) * td.delta.invert().unwrap();
+
+    let (g1, g2) = (G1Projective::generator(), G2Projective::generator());
+    let proof = Proof {
+        a: (g1 * a).to_affine(),
+        b: (g2 * b).to_affine(),
+        c: (g1 * c).to_affine(),
+    };
+    (proof, inputs)
+}
+
+fn groth16_setup(rng: &mut impl rand::RngCore, n: usize) -> (VerifyingKey, Proof, Vec<Scalar>) {
+    let (vk, td) = groth16_keys(rng, n);
+    let (proof, inputs) = groth16_proof(rng, &td);
+    (vk, proof, inputs)
+}
+
+// The pairing check costs the same for any circuit, so verification only grows
+// with the number of public inputs, here from 2 to 1024.
+fn bench_groth16_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group("bls12_381/groth16_verify");
+    for logsize in 1..=10 {
+        let n = 1 << logsize;
+        let (vk, proof, inputs) = groth16_setup(&mut rng, n);
+        assert!(groth16_verify(&vk, &proof, &inputs));
+
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| groth16_verify(black_box(&vk), black_box(&proof), black_box(&inputs)))
+        });
+    }
+    group.finish()
+}
+
+// Checks N proofs against the same key at once: for random r_i,
+// prod e(r_i A_i, B_i) = e(alpha, beta)^(sum r_i) e(sum r_i L_i, gamma) e(sum r_i C_i, delta).
+// The public inputs are combined before the MSM, as
+// sum r_i L_i = (sum r_i) ic_0 + sum_j (sum_i r_i x_ij) ic_(j + 1), so that
+// there is one public-input MSM and one final exponentiation for any N.
+fn groth16_batch_verify(
+    vk: &VerifyingKey,
+    proofs: &[(Proof, Vec<Scalar>)],
+    rng: &mut impl rand::RngCore,
+) -> bool {
+    let r: Vec<_> = proofs.iter().map(|_| Scalar::random(&mut *rng)).collect();
+    let mut scalars = vec![Scalar::ZERO; vk.ic.len()];
+    for ((_, inputs), r) in proofs.iter().zip(&r) {
+        scalars[0] += r;
+        for (s, x) in scalars[1..].iter_mut().zip(inputs) {
+            *s += r * x;
+        }
+    }
+    let l = msm::<Bls12_381>(&vk.ic, &scalars).unwrap();
+    let c: Vec<_> = proofs.iter().map(|(proof, _)| proof.c.into()).collect();
+    let c = msm::<Bls12_381>(&c, &r).unwrap();
+
+    let ra: Vec<_> = proofs
+        .iter()
+        .zip(&r)
+        .map(|((proof, _), r)| proof.a * r)
+        .collect();
+    let mut g1 = vec![G1Affine::default(); proofs.len() + 2];
+    G1Projective::batch_normalize(&ra, &mut g1[..proofs.len()]);
+    g1[proofs.len()] = (-l).to_affine();
+    g1[proofs.len() + 1] = (-c).to_affine();
+    let b: Vec<_> = proofs
+        .iter()
+        .map(|(proof, _)| G2Prepared::from(proof.b))
+        .collect();
+
+    let mut pairs: Vec<_> = g1.iter().zip(&b).collect();
+    pairs.push((&g1[proofs.len()], &vk.gamma));
+    pairs.push((&g1[proofs.len() + 1], &vk.delta));
+    Bls12::multi_miller_loop(&pairs).final_exponentiation() == vk.alpha_beta * scalars[0]
+}
+
+// N proofs of a circuit with 16 public inputs, verified in a batch or one by
+// one. Drawing the r_i is part of the batched check.
+fn bench_groth16_batch_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let (vk, td) = groth16_keys(&mut rng, 16);
+    let mut group = c.benchmark_group("bls12_381/groth16_batch_verify");
+    for logsize in 0..=6 {
+        if logsize > 4 {
+            group.sample_size(10);
+        }
+        let n = 1 << logsize;
+        let proofs: Vec<_> = (0..n).map(|_| groth16_proof(&mut rng, &td)).collect();
+        assert!(groth16_batch_verify(&vk, &proofs, &mut rng));
+        let (mut bad, inputs) = proofs[0].clone();
+        bad.c = (G1Projective::from(bad.c) + G1Projective::generator()).to_affine();
+        let mut wrong = proofs.clone();
+        wrong[0] = (bad, inputs);
+        assert!(!groth16_batch_verify(&vk, &wrong, &mut rng));
+
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::new("batched", n), &n, |b, _| {
+            b.iter(|| groth16_batch_verify(black_box(&vk), black_box(&proofs), &mut rng))
+        });
+        group.bench_with_input(BenchmarkId::new("individual", n), &n, |b, _| {
+            b.iter(|| {
+                proofs
+                    .iter()
+                    .all(|(proof, inputs)| groth16_verify(black_box(&vk), proof, inputs))
+            })
+        });
+    }
+    group.finish()
+}
+
+criterion_group!(
+    groth16_benchmarks,
+    bench_groth16_prover_msms,
+    bench_groth16_verify,
+    bench_groth16_batch_verify,
+);
+criterion_main!(groth16_benchmarks);
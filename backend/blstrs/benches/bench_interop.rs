@@ -0,0 +1,77 @@
+//! Converting scalars and G1 points between blstrs and arkworks, as stacks that
+//! prove with one library and verify with the other do, through the byte
+//! encodings that both libraries share.
+//!
+//! Both sides trust each other's points, so they are converted in uncompressed
+//! form and without the subgroup check.
+
+use ark_bls12_381::g1::{G1_GENERATOR_X, G1_GENERATOR_Y};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use blstrs::{G1Affine, G1Projective, Scalar};
+use criterion::*;
+use ff::Field;
+use group::prime::PrimeCurveAffine;
+use group::{Curve, Group};
+use zkalc_common::config;
+
+fn scalar_to_ark(s: &Scalar) -> ark_bls12_381::Fr {
+    ark_bls12_381::Fr::deserialize_uncompressed_unchecked(&s.to_bytes_le()[..]).unwrap()
+}
+
+fn scalar_from_ark(s: &ark_bls12_381::Fr) -> Scalar {
+    let mut bytes = [0u8; 32];
+    s.serialize_uncompressed(&mut bytes[..]).unwrap();
+    Scalar::from_bytes_le(&bytes).unwrap()
+}
+
+fn g1_to_ark(p: &G1Affine) -> ark_bls12_381::G1Affine {
+    ark_bls12_381::G1Affine::deserialize_uncompressed_unchecked(&p.to_uncompressed()[..]).unwrap()
+}
+
+fn g1_from_ark(p: &ark_bls12_381::G1Affine) -> G1Affine {
+    let mut bytes = [0u8; 96];
+    p.serialize_uncompressed(&mut bytes[..]).unwrap();
+    G1Affine::from_uncompressed_unchecked(&bytes).unwrap()
+}
+
+fn bench_scalar_convert_ark_blst(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let s = Scalar::random(&mut rng);
+    let ark = scalar_to_ark(&s);
+    assert_eq!(scalar_from_ark(&ark), s);
+
+    let mut group = c.benchmark_group("bls12_381/convert");
+    group.bench_function("scalar/blst_to_ark", |b| {
+        b.iter(|| scalar_to_ark(black_box(&s)))
+    });
+    group.bench_function("scalar/ark_to_blst", |b| {
+        b.iter(|| scalar_from_ark(black_box(&ark)))
+    });
+    group.finish()
+}
+
+fn bench_g1_convert_ark_blst(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let p = G1Projective::random(&mut rng).to_affine();
+    let ark = g1_to_ark(&p);
+    assert_eq!(g1_from_ark(&ark), p);
+    // The round trip alone would pass for any two encodings of the same size
+    assert!(ark.is_on_curve());
+    let ark_generator = ark_bls12_381::G1Affine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
+    assert_eq!(g1_to_ark(&G1Affine::generator()), ark_generator);
+
+    let mut group = c.benchmark_group("bls12_381/convert");
+    group.bench_function("G1/blst_to_ark", |b| b.iter(|| g1_to_ark(black_box(&p))));
+    group.bench_function("G1/ark_to_blst", |b| {
+        b.iter(|| g1_from_ark(black_box(&ark)))
+    });
+    group.finish()
+}
+
+criterion_group!(
+    interop_benchmarks,
+    bench_scalar_convert_ark_blst,
+    bench_g1_convert_ark_blst,
+);
+
+criterion_main!(interop_benchmarks);
@@ -0,0 +1,69 @@
+//! The inner product argument of Bulletproofs and Halo, proving and verifying
+//! over generator vectors of increasing size.
+
+use blstrs::Scalar;
+use blstrs_benchmarks::ipa::Generators;
+use criterion::*;
+use ff::Field;
+use zkalc_common::config;
+
+struct IpaInstance {
+    generators: Generators,
+    a: Vec<Scalar>,
+    b: Vec<Scalar>,
+    challenges: Vec<Scalar>,
+}
+
+fn ipa_instance(logsize: usize, rng: &mut impl rand::RngCore) -> IpaInstance {
+    let size = 1 << logsize;
+    IpaInstance {
+        generators: Generators::random(size, &mut *rng),
+        a: (0..size).map(|_| Scalar::random(&mut *rng)).collect(),
+        b: (0..size).map(|_| Scalar::random(&mut *rng)).collect(),
+        challenges: (0..logsize).map(|_| Scalar::random(&mut *rng)).collect(),
+    }
+}
+
+// The prover folds both generator vectors in every round, two scalar
+// multiplications per point, so it is linear in the size while the verifier's
+// single MSM is sublinear.
+fn bench_ipa_prove(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/ipa");
+    for logsize in 2..=10 {
+        if logsize > 7 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let ipa = ipa_instance(logsize, &mut rng);
+
+        group.bench_with_input(BenchmarkId::new("prove", size), &size, |r, _| {
+            r.iter(|| ipa.generators.prove(&ipa.a, &ipa.b, &ipa.challenges))
+        });
+    }
+
+    group.finish()
+}
+
+fn bench_ipa_verify(c: &mut Criterion) {
+    let mut rng = config::rng();
+
+    let mut group = c.benchmark_group("bls12_381/ipa");
+    for logsize in 2..=10 {
+        let size = 1 << logsize;
+        let ipa = ipa_instance(logsize, &mut rng);
+        let commitment = ipa.generators.commit(&ipa.a, &ipa.b);
+        let proof = ipa.generators.prove(&ipa.a, &ipa.b, &ipa.challenges);
+        assert!(ipa.generators.verify(&commitment, &proof, &ipa.challenges));
+
+        group.bench_with_input(BenchmarkId::new("verify", size), &size, |r, _| {
+            r.iter(|| ipa.generators.verify(&commitment, &proof, &ipa.challenges))
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(ipa_benchmarks, bench_ipa_prove, bench_ipa_verify,);
+criterion_main!(ipa_benchmarks);
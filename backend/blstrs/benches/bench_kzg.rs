@@ -0,0 +1,65 @@
+//! KZG commitments: committing to a polynomial, opening it at a point, and
+//! opening several polynomials at the same point with one proof.
+
+use blstrs::Scalar;
+use blstrs_benchmarks::kzg::Srs;
+use criterion::*;
+use ff::Field;
+use zkalc_common::config;
+
+// One SRS of the largest degree is generated up front and sliced for the
+// smaller polynomials.
+fn bench_kzg(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let max_logsize = 20;
+    let srs = Srs::random(1 << max_logsize, &mut rng);
+
+    let mut group = c.benchmark_group("bls12_381/kzg");
+    for logsize in 10..=max_logsize {
+        if logsize > 18 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let coeffs: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let z = Scalar::random(&mut rng);
+
+        group.bench_with_input(BenchmarkId::new("commit", size), &size, |b, _| {
+            b.iter(|| srs.commit(&coeffs))
+        });
+        group.bench_with_input(BenchmarkId::new("open", size), &size, |b, _| {
+            b.iter(|| srs.open(&coeffs, black_box(&z)))
+        });
+    }
+
+    group.finish()
+}
+
+// Opening N polynomials of 2^16 coefficients at one point with a single
+// proof. Combining them costs a multiplication and an addition per
+// coefficient, but there is still one division and one MSM, so the per
+// polynomial throughput shows how far that is amortized.
+fn bench_kzg_batch_open(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let size = 1 << 16;
+    let srs = Srs::random(size, &mut rng);
+    let z = Scalar::random(&mut rng);
+    let gamma = Scalar::random(&mut rng);
+
+    let mut group = c.benchmark_group("bls12_381/kzg_batch_open");
+    group.sample_size(10);
+    for logcount in 0..=6 {
+        let count = 1 << logcount;
+        let polys: Vec<Vec<_>> = (0..count)
+            .map(|_| (0..size).map(|_| Scalar::random(&mut rng)).collect())
+            .collect();
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &count, |b, _| {
+            b.iter(|| srs.batch_open(&polys, black_box(&z), black_box(&gamma)))
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(kzg_benchmarks, bench_kzg, bench_kzg_batch_open,);
+criterion_main!(kzg_benchmarks);
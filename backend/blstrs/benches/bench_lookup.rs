@@ -0,0 +1,69 @@
+//! A logUp lookup argument's commitment to its running sum.
+
+use blstrs::{G1Projective, Scalar};
+use blstrs_benchmarks::curve::{cheap_points, Bls12_381};
+use blstrs_benchmarks::field::batch_invert;
+use criterion::*;
+use ff::Field;
+use rand::Rng;
+use zkalc_common::config;
+use zkalc_common::msm::msm;
+
+// The logUp running sum of witness `f` looked up in table `t` with
+// multiplicities `m`: `phi_0 = 0` and
+// `phi_(i+1) = phi_i + 1 / (alpha + f_i) - m_i / (alpha + t_i)`, which ends at
+// zero exactly if every `f_i` is in the table. All 2n denominators are
+// inverted at once.
+fn logup_accumulator(f: &[Scalar], t: &[Scalar], m: &[Scalar], alpha: Scalar) -> Vec<Scalar> {
+    let mut inverses: Vec<_> = f.iter().chain(t).map(|x| alpha + x).collect();
+    batch_invert(&mut inverses);
+    let (f_inv, t_inv) = inverses.split_at(f.len());
+    f_inv
+        .iter()
+        .zip(t_inv)
+        .zip(m)
+        .scan(Scalar::ZERO, |phi, ((f_inv, t_inv), m)| {
+            *phi += f_inv - m * t_inv;
+            Some(*phi)
+        })
+        .collect()
+}
+
+// One logUp commitment: building the accumulator for a witness and a table of
+// the same size and committing to it with an MSM. Committing to the
+// multiplicities and the helper columns, and the sumcheck or quotient proving
+// the accumulator's steps, are not included.
+fn bench_lookup_commit(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let max_logsize = 20;
+    let bases: Vec<G1Projective> = cheap_points(1 << max_logsize, &mut rng);
+
+    let mut group = c.benchmark_group("bls12_381/lookup_commit");
+    for logsize in 10..=max_logsize {
+        if logsize > 16 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let t: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let mut m = vec![Scalar::ZERO; size];
+        let f: Vec<_> = (0..size)
+            .map(|_| {
+                let i = rng.gen_range(0..size);
+                m[i] += Scalar::ONE;
+                t[i]
+            })
+            .collect();
+        let alpha = Scalar::random(&mut rng);
+        assert_eq!(logup_accumulator(&f, &t, &m, alpha)[size - 1], Scalar::ZERO);
+        let bases = &bases[..size];
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| msm::<Bls12_381>(bases, &logup_accumulator(&f, &t, &m, alpha)).unwrap())
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(lookup_benchmarks, bench_lookup_commit,);
+criterion_main!(lookup_benchmarks);
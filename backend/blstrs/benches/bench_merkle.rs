@@ -0,0 +1,84 @@
+//! Merkle tree construction, up to the root or a cap, with an arithmetic hash
+//! (Poseidon) and a byte hash (SHA-256), over the same leaf counts.
+
+use blstrs::Scalar;
+use blstrs_benchmarks::merkle::{cap, root, MerkleHash, PoseidonHash, Sha256Hash};
+use criterion::*;
+use ff::Field;
+use rand::RngCore;
+use zkalc_common::config;
+
+fn bench_merkle_build_with<H: MerkleHash>(
+    c: &mut Criterion,
+    name: &str,
+    hash: &H,
+    mut leaf: impl FnMut() -> H::Digest,
+) {
+    let mut group = c.benchmark_group("bls12_381/merkle_build");
+    for logsize in 4..=16 {
+        // Dynamically control sample size so that big trees don't bench eternally
+        if logsize > 12 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let leaves: Vec<_> = (0..size).map(|_| leaf()).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new(name, size), &size, |b, _| {
+            b.iter(|| root(hash, &leaves))
+        });
+    }
+    group.finish()
+}
+
+fn bench_merkle_build(c: &mut Criterion) {
+    let mut rng = config::rng();
+    bench_merkle_build_with(c, "poseidon", &PoseidonHash::default(), || {
+        Scalar::random(&mut rng)
+    });
+    bench_merkle_build_with(c, "sha256", &Sha256Hash, || {
+        let mut leaf = [0u8; 32];
+        rng.fill_bytes(&mut leaf);
+        leaf
+    });
+}
+
+// A cap of height h saves the top 2^h - 1 hashes of the tree, and is sent in
+// place of the root: a FRI commitment trades proof size for hashing with it.
+fn bench_merkle_cap_with<H: MerkleHash>(
+    c: &mut Criterion,
+    name: &str,
+    hash: &H,
+    mut leaf: impl FnMut() -> H::Digest,
+) {
+    let mut group = c.benchmark_group("bls12_381/merkle_cap");
+    for logsize in 4..=16 {
+        if logsize > 12 {
+            group.sample_size(10);
+        }
+        let size = 1 << logsize;
+        let leaves: Vec<_> = (0..size).map(|_| leaf()).collect();
+        group.throughput(Throughput::Elements(size as u64));
+        for cap_height in [1, 2, 4] {
+            let id = format!("{}/cap_{}", name, cap_height);
+            group.bench_with_input(BenchmarkId::new(id, size), &size, |b, _| {
+                b.iter(|| cap(hash, &leaves, cap_height))
+            });
+        }
+    }
+    group.finish()
+}
+
+fn bench_merkle_cap(c: &mut Criterion) {
+    let mut rng = config::rng();
+    bench_merkle_cap_with(c, "poseidon", &PoseidonHash::default(), || {
+        Scalar::random(&mut rng)
+    });
+    bench_merkle_cap_with(c, "sha256", &Sha256Hash, || {
+        let mut leaf = [0u8; 32];
+        rng.fill_bytes(&mut leaf);
+        leaf
+    });
+}
+
+criterion_group!(merkle_benchmarks, bench_merkle_build, bench_merkle_cap);
+criterion_main!(merkle_benchmarks);
@@ -0,0 +1,58 @@
+//! Poseidon over the BLS12-381 scalar field, using neptune's sponge.
+//!
+//! A width-t permutation absorbs t-1 elements at a time, so the cost of a hash
+//! grows in steps of t-1 input elements.
+
+use blstrs::Scalar;
+use criterion::*;
+use ff::Field;
+use generic_array::typenum::{U2, U4};
+use neptune::sponge::api::{IOPattern, SpongeAPI, SpongeOp};
+use neptune::sponge::vanilla::{Mode, Sponge, SpongeTrait};
+use neptune::{Arity, Strength};
+use zkalc_common::config;
+
+/// Hashes `inputs` to a single element.
+fn hash<A: Arity<Scalar>>(
+    constants: &neptune::poseidon::PoseidonConstants<Scalar, A>,
+    inputs: &[Scalar],
+) -> Scalar {
+    let n = inputs.len() as u32;
+    let acc = &mut ();
+    let mut sponge = Sponge::new_with_constants(constants, Mode::Simplex);
+    sponge.start(
+        IOPattern(vec![SpongeOp::Absorb(n), SpongeOp::Squeeze(1)]),
+        None,
+        acc,
+    );
+    SpongeAPI::absorb(&mut sponge, n, inputs, acc);
+    let output = SpongeAPI::squeeze(&mut sponge, 1, acc);
+    sponge.finish(acc).unwrap();
+    output[0]
+}
+
+fn bench_poseidon_width<A: Arity<Scalar>>(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let width = A::to_usize() + 1;
+    let constants = Sponge::<Scalar, A>::api_constants(Strength::Standard);
+
+    let mut group = c.benchmark_group(format!("bls12_381/poseidon/t{}", width));
+    for length in [1, 2, 4, 8, 16, 32, 64] {
+        let inputs: Vec<_> = (0..length).map(|_| Scalar::random(&mut rng)).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(length), &length, |b, _| {
+            b.iter(|| hash(&constants, black_box(&inputs)))
+        });
+    }
+    group.finish()
+}
+
+fn bench_poseidon_t3(c: &mut Criterion) {
+    bench_poseidon_width::<U2>(c)
+}
+
+fn bench_poseidon_t5(c: &mut Criterion) {
+    bench_poseidon_width::<U4>(c)
+}
+
+criterion_group!(poseidon_benchmarks, bench_poseidon_t3, bench_poseidon_t5);
+criterion_main!(poseidon_benchmarks);
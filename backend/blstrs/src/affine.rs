@@ -0,0 +1,72 @@
+//! Addition of G1 points in affine coordinates.
+//!
+//! blstrs only adds in projective coordinates. An affine addition needs a
+//! field inversion for its slope, which makes it slower on its own, but the
+//! inversions of many independent additions can be shared with Montgomery's
+//! trick, as MSMs do to sum their buckets.
+//!
+//! Neither function handles doubling, the identity or opposite points, which
+//! random inputs almost surely avoid.
+
+use blstrs::{Fp, G1Affine};
+use ff::Field;
+
+/// `p + q`, with `p != ±q` and neither the identity.
+pub fn add(p: &G1Affine, q: &G1Affine) -> G1Affine {
+    let lambda = (q.y() - p.y()) * (q.x() - p.x()).invert().unwrap();
+    add_with_slope(p, q, lambda)
+}
+
+/// `p_i + q_i` for every pair, with a single field inversion and three
+/// multiplications per pair to invert all the denominators `x(q_i) - x(p_i)`.
+pub fn batch_add(pairs: &[(G1Affine, G1Affine)]) -> Vec<G1Affine> {
+    let mut prefix = Vec::with_capacity(pairs.len());
+    let mut acc = Fp::ONE;
+    for (p, q) in pairs {
+        prefix.push(acc);
+        acc *= q.x() - p.x();
+    }
+
+    let mut inv = acc.invert().unwrap();
+    let mut sums = vec![G1Affine::default(); pairs.len()];
+    for ((sum, (p, q)), prefix) in sums.iter_mut().zip(pairs).zip(prefix).rev() {
+        let dx = q.x() - p.x();
+        *sum = add_with_slope(p, q, (q.y() - p.y()) * inv * prefix);
+        inv *= dx;
+    }
+    sums
+}
+
+fn add_with_slope(p: &G1Affine, q: &G1Affine, lambda: Fp) -> G1Affine {
+    let x = lambda.square() - p.x() - q.x();
+    let y = lambda * (p.x() - x) - p.y();
+    G1Affine::from_raw_unchecked(x, y, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blstrs::G1Projective;
+    use group::{Curve, Group};
+    use zkalc_common::config;
+
+    #[test]
+    fn matches_projective_addition() {
+        let mut rng = config::rng();
+        let pairs: Vec<_> = (0..5)
+            .map(|_| {
+                (
+                    G1Projective::random(&mut rng).to_affine(),
+                    G1Projective::random(&mut rng).to_affine(),
+                )
+            })
+            .collect();
+        let expected: Vec<_> = pairs
+            .iter()
+            .map(|(p, q)| (G1Projective::from(p) + q).to_affine())
+            .collect();
+        let independent: Vec<_> = pairs.iter().map(|(p, q)| add(p, q)).collect();
+        assert_eq!(independent, expected);
+        assert_eq!(batch_add(&pairs), expected);
+    }
+}
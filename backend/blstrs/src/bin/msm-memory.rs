@@ -0,0 +1,18 @@
+//! Prints the peak heap usage and the time of one G1 MSM per size of the MSM
+//! sweep, as a results file. Build it with `--features memory`.
+
+#[cfg(any(feature = "jemalloc", feature = "mimalloc"))]
+compile_error!("msm-memory counts the allocations of the system allocator");
+
+use blstrs_benchmarks::curve::Bls12_381;
+use zkalc_common::memory::{msm_memory, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    if let Err(e) = msm_memory::<Bls12_381>().to_writer(std::io::stdout().lock()) {
+        eprintln!("msm-memory: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,74 @@
+//! Cofactor clearing by multiplication with the effective cofactors of the
+//! hash-to-curve specification (RFC 9380, section 8.8.2), and random curve
+//! points outside the prime-order subgroups to clear.
+//!
+//! blstrs only clears cofactors inside `hash_to_curve`, so this is the plain
+//! scalar multiplication; blst's internal G2 routine uses the faster
+//! endomorphism-based method, which this upper-bounds.
+
+use std::sync::OnceLock;
+
+use blstrs::{Fp, Fp2, G1Affine, G1Projective, G2Affine, G2Projective};
+use ff::Field;
+use group::Group;
+use rand::RngCore;
+
+/// `h_eff` for G1, `1 - z` with `z = -0xd201000000010000`, little-endian.
+const G1_H_EFF: [u8; 8] = 0xd201000000010001u64.to_le_bytes();
+
+/// `h_eff` for G2 (636 bits), big-endian as in the specification.
+const G2_H_EFF: &str = "0bc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551";
+
+/// Left-to-right double-and-add over the little-endian `scalar`.
+fn mul_by_le_bytes<G: Group>(p: &G, scalar: &[u8]) -> G {
+    let mut acc = G::identity();
+    for i in (0..8 * scalar.len()).rev() {
+        acc = acc.double();
+        if (scalar[i / 8] >> (i % 8)) & 1 == 1 {
+            acc += p;
+        }
+    }
+    acc
+}
+
+fn g2_h_eff() -> Vec<u8> {
+    let mut bytes: Vec<u8> = (0..G2_H_EFF.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&G2_H_EFF[i..i + 2], 16).unwrap())
+        .collect();
+    bytes.reverse();
+    bytes
+}
+
+/// Maps any point of E(Fp) into G1.
+pub fn clear_cofactor_g1(p: &G1Projective) -> G1Projective {
+    mul_by_le_bytes(p, &G1_H_EFF)
+}
+
+/// Maps any point of E'(Fp2) into G2.
+pub fn clear_cofactor_g2(p: &G2Projective) -> G2Projective {
+    static H_EFF: OnceLock<Vec<u8>> = OnceLock::new();
+    mul_by_le_bytes(p, H_EFF.get_or_init(g2_h_eff))
+}
+
+/// A random point of E(Fp): y^2 = x^3 + 4, almost surely not in G1.
+pub fn random_g1_point(mut rng: impl RngCore) -> G1Projective {
+    let b = Fp::from(4);
+    loop {
+        let x = Fp::random(&mut rng);
+        if let Some(y) = Option::<Fp>::from((x.square() * x + b).sqrt()) {
+            return G1Affine::from_raw_unchecked(x, y, false).into();
+        }
+    }
+}
+
+/// A random point of E'(Fp2): y^2 = x^3 + 4(1 + u), almost surely not in G2.
+pub fn random_g2_point(mut rng: impl RngCore) -> G2Projective {
+    let b = Fp2::new(Fp::from(4), Fp::from(4));
+    loop {
+        let x = Fp2::random(&mut rng);
+        if let Some(y) = Option::<Fp2>::from((x.square() * x + b).sqrt()) {
+            return G2Affine::from_raw_unchecked(x, y, false).into();
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! BLS12-381 as a [`zkalc_common::curve::Curve`], for the benchmarks shared
+//! by all backends, and cheap MSM bases for the protocol benchmarks.
+
+use blstrs::{G1Affine, G1Projective, G2Affine, G2Projective, Gt, Scalar};
+use ff::Field;
+use group::{Curve as _, Group};
+use pairing_lib::PairingCurveAffine;
+use rand::RngCore;
+use zkalc_common::curve::{Curve, PairingCurve};
+
+pub struct Bls12_381;
+
+impl Curve for Bls12_381 {
+    const NAME: &'static str = "bls12_381";
+
+    type Scalar = Scalar;
+    type G1 = G1Projective;
+    type G1Base = G1Projective;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> Scalar {
+        Scalar::random(rng)
+    }
+
+    fn rand_g1(rng: &mut impl RngCore) -> G1Projective {
+        G1Projective::random(rng)
+    }
+
+    fn invert(x: &Scalar) -> Option<Scalar> {
+        x.invert().into()
+    }
+
+    fn g1_base(p: &G1Projective) -> G1Projective {
+        *p
+    }
+
+    // blstrs' multi_exp gets a single term wrong
+    fn msm_g1(bases: &[G1Projective], scalars: &[Scalar]) -> G1Projective {
+        match bases {
+            [base] => base * scalars[0],
+            _ => G1Projective::multi_exp(bases, scalars),
+        }
+    }
+}
+
+impl PairingCurve for Bls12_381 {
+    type G2 = G2Projective;
+    type G2Base = G2Projective;
+    type G1Affine = G1Affine;
+    type G2Affine = G2Affine;
+    type Gt = Gt;
+
+    fn rand_g2(rng: &mut impl RngCore) -> G2Projective {
+        G2Projective::random(rng)
+    }
+
+    fn g2_base(p: &G2Projective) -> G2Projective {
+        *p
+    }
+
+    fn msm_g2(bases: &[G2Projective], scalars: &[Scalar]) -> G2Projective {
+        match bases {
+            [base] => base * scalars[0],
+            _ => G2Projective::multi_exp(bases, scalars),
+        }
+    }
+
+    fn g1_to_affine(p: &G1Projective) -> G1Affine {
+        p.to_affine()
+    }
+
+    fn g2_to_affine(p: &G2Projective) -> G2Affine {
+        p.to_affine()
+    }
+
+    fn pairing(a: &G1Affine, b: &G2Affine) -> Gt {
+        a.pairing_with(b)
+    }
+}
+
+/// `n` distinct points `start + i * step` for random `start` and `step`: one
+/// addition each instead of a random point each, and an MSM does not care how
+/// its bases are related.
+pub fn cheap_points<G: Group>(n: usize, rng: &mut impl RngCore) -> Vec<G> {
+    let step = G::random(&mut *rng);
+    (0..n)
+        .scan(G::random(&mut *rng), |acc, _| {
+            *acc += step;
+            Some(*acc)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkalc_common::{config, msm};
+
+    #[test]
+    fn msm_matches_naive_sum() {
+        let mut rng = config::rng();
+        for size in [1, 2, 3, 8, 33] {
+            let scalars: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+            let g1: Vec<_> = (0..size).map(|_| G1Projective::random(&mut rng)).collect();
+            let g2: Vec<_> = (0..size).map(|_| G2Projective::random(&mut rng)).collect();
+            let naive_g1: G1Projective = g1.iter().zip(&scalars).map(|(p, s)| p * s).sum();
+            let naive_g2: G2Projective = g2.iter().zip(&scalars).map(|(p, s)| p * s).sum();
+            assert_eq!(Bls12_381::msm_g1(&g1, &scalars), naive_g1);
+            assert_eq!(Bls12_381::msm_g2(&g2, &scalars), naive_g2);
+        }
+    }
+
+    #[test]
+    fn msm_wrapper_handles_a_single_term() {
+        let mut rng = config::rng();
+        let s = Scalar::random(&mut rng);
+        let (p, q) = (
+            G1Projective::random(&mut rng),
+            G2Projective::random(&mut rng),
+        );
+        assert_eq!(msm::msm::<Bls12_381>(&[p], &[s]), Ok(p * s));
+        assert_eq!(msm::msm_g2::<Bls12_381>(&[q], &[s]), Ok(q * s));
+    }
+
+    #[test]
+    fn invert_is_an_inverse() {
+        let x = Bls12_381::rand_scalar(&mut config::rng());
+        assert_eq!(Bls12_381::invert(&x).unwrap() * x, Scalar::ONE);
+        assert_eq!(Bls12_381::invert(&Scalar::ZERO), None);
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let mut rng = config::rng();
+        let (a, b) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let (g1, g2) = (G1Projective::generator(), G2Projective::generator());
+        let e = Bls12_381::pairing(&g1.to_affine(), &g2.to_affine());
+        assert_ne!(e, Gt::identity());
+        let e_ab = Bls12_381::pairing(&(g1 * a).to_affine(), &(g2 * b).to_affine());
+        assert_eq!(e_ab, e * (a * b));
+    }
+}
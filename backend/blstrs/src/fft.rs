@@ -0,0 +1,220 @@
+//! Radix-2 FFTs over the BLS12-381 scalar field.
+//!
+//! blstrs ships no polynomial arithmetic, so this is a plain iterative
+//! Cooley–Tukey implementation with precomputed twiddle factors.
+
+use blstrs::Scalar;
+use ff::{Field, PrimeField};
+
+use crate::field::powers;
+
+/// The multiplicative subgroup of order `2^log_size`, with its twiddle factors.
+pub struct Domain {
+    log_size: u32,
+    omega: Scalar,
+    size_inv: Scalar,
+    twiddles: Vec<Scalar>,
+    inv_twiddles: Vec<Scalar>,
+}
+
+impl Domain {
+    /// Builds the domain of size `2^log_size`, precomputing `omega^i` and
+    /// `omega^-i` for `i < 2^(log_size - 1)`.
+    pub fn new(log_size: u32) -> Self {
+        assert!(
+            log_size <= Scalar::S,
+            "the scalar field has no subgroup of order 2^{}",
+            log_size
+        );
+
+        let omega = root_of_unity(log_size);
+        let omega_inv = omega.invert().unwrap();
+        let size_inv = Scalar::from(1u64 << log_size).invert().unwrap();
+
+        Domain {
+            log_size,
+            omega,
+            size_inv,
+            twiddles: powers(omega, 1 << log_size.saturating_sub(1)),
+            inv_twiddles: powers(omega_inv, 1 << log_size.saturating_sub(1)),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        1 << self.log_size
+    }
+
+    /// The generator of the domain.
+    pub fn omega(&self) -> Scalar {
+        self.omega
+    }
+
+    /// Evaluates the vanishing polynomial of the domain, `x^n - 1`, with
+    /// `log2(n)` squarings.
+    pub fn evaluate_vanishing(&self, x: Scalar) -> Scalar {
+        (0..self.log_size).fold(x, |acc, _| acc.square()) - Scalar::ONE
+    }
+
+    /// Evaluates the polynomial with coefficients `a` over the domain, in place.
+    pub fn fft(&self, a: &mut [Scalar]) {
+        assert_eq!(a.len(), self.size());
+        serial_fft(a, &self.twiddles, self.log_size);
+    }
+
+    /// Interpolates the evaluations `a` over the domain into coefficients, in place.
+    pub fn ifft(&self, a: &mut [Scalar]) {
+        assert_eq!(a.len(), self.size());
+        serial_fft(a, &self.inv_twiddles, self.log_size);
+        for x in a.iter_mut() {
+            *x *= self.size_inv;
+        }
+    }
+
+    /// Evaluates the polynomial with coefficients `a` over the coset
+    /// `g * <omega>`, `g` the multiplicative generator of the field, in place.
+    pub fn coset_fft(&self, a: &mut [Scalar]) {
+        distribute_powers(a, Scalar::MULTIPLICATIVE_GENERATOR);
+        self.fft(a);
+    }
+
+    /// Interpolates the evaluations `a` over the coset `g * <omega>` into
+    /// coefficients, in place.
+    pub fn coset_ifft(&self, a: &mut [Scalar]) {
+        self.ifft(a);
+        distribute_powers(a, Scalar::MULTIPLICATIVE_GENERATOR.invert().unwrap());
+    }
+
+    /// One FRI folding round with challenge `beta`. Given the evaluations of
+    /// `f(X) = f_even(X^2) + X f_odd(X^2)` over the domain, returns those of
+    /// `f_even + beta * f_odd` over the domain of half the size, generated by
+    /// `omega^2`.
+    ///
+    /// As `omega^(n/2) = -1`, the evaluations at `x` and `-x` are half the
+    /// domain apart, and `f_even(x^2) = (f(x) + f(-x)) / 2`,
+    /// `f_odd(x^2) = (f(x) - f(-x)) / 2x`.
+    pub fn fri_fold(&self, evals: &[Scalar], beta: Scalar) -> Vec<Scalar> {
+        assert_eq!(evals.len(), self.size());
+        let half = self.size() / 2;
+        let two_inv = Scalar::from(2u64).invert().unwrap();
+        let (lo, hi) = evals.split_at(half);
+        lo.iter()
+            .zip(hi)
+            .zip(&self.inv_twiddles)
+            .map(|((a, b), x_inv)| (a + b + beta * x_inv * (a - b)) * two_inv)
+            .collect()
+    }
+}
+
+/// The generator of the subgroup of order `2^log_size`, by squaring the
+/// field's `2^S`-th root of unity.
+fn root_of_unity(log_size: u32) -> Scalar {
+    (log_size..Scalar::S).fold(Scalar::ROOT_OF_UNITY, |omega, _| omega.square())
+}
+
+/// The twiddle factors of the FFT over the domain of size `2^log_size`,
+/// `omega^i` for `i < 2^(log_size - 1)`, as [`Domain::new`] precomputes them.
+pub fn twiddle_factors(log_size: u32) -> Vec<Scalar> {
+    powers(root_of_unity(log_size), 1 << log_size.saturating_sub(1))
+}
+
+/// Multiplies `a[i]` by `g^i`, which shifts the evaluation domain by `g`.
+fn distribute_powers(a: &mut [Scalar], g: Scalar) {
+    let mut acc = Scalar::ONE;
+    for x in a.iter_mut() {
+        *x *= acc;
+        acc *= g;
+    }
+}
+
+fn bit_reverse_permutation(a: &mut [Scalar], log_size: u32) {
+    if log_size == 0 {
+        return;
+    }
+    for i in 0..a.len() {
+        let j = i.reverse_bits() >> (usize::BITS - log_size);
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+fn serial_fft(a: &mut [Scalar], twiddles: &[Scalar], log_size: u32) {
+    let n = a.len();
+    bit_reverse_permutation(a, log_size);
+
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        for chunk in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for (j, (x, y)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                let t = *y * twiddles[j * stride];
+                *y = *x - t;
+                *x += t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(coeffs: &[Scalar], x: Scalar) -> Scalar {
+        coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+    }
+
+    fn coeffs(n: u64) -> Vec<Scalar> {
+        (1..=n).map(|i| Scalar::from(i * i + 7)).collect()
+    }
+
+    #[test]
+    fn fft_evaluates_over_the_domain() {
+        let domain = Domain::new(3);
+        let coeffs = coeffs(8);
+        let mut evals = coeffs.clone();
+        domain.fft(&mut evals);
+        for (i, e) in evals.iter().enumerate() {
+            assert_eq!(
+                *e,
+                evaluate(&coeffs, domain.omega().pow_vartime([i as u64]))
+            );
+        }
+        domain.ifft(&mut evals);
+        assert_eq!(evals, coeffs);
+    }
+
+    #[test]
+    fn coset_fft_evaluates_over_the_coset() {
+        let domain = Domain::new(3);
+        let coeffs = coeffs(8);
+        let mut evals = coeffs.clone();
+        domain.coset_fft(&mut evals);
+        let x = Scalar::MULTIPLICATIVE_GENERATOR * domain.omega();
+        assert_eq!(evals[1], evaluate(&coeffs, x));
+        domain.coset_ifft(&mut evals);
+        assert_eq!(evals, coeffs);
+    }
+
+    #[test]
+    fn fri_fold_folds_even_and_odd_parts() {
+        let (domain, half) = (Domain::new(3), Domain::new(2));
+        let coeffs = coeffs(8);
+        let beta = Scalar::from(5u64);
+        let mut evals = coeffs.clone();
+        domain.fft(&mut evals);
+
+        let mut folded: Vec<_> = coeffs.chunks(2).map(|c| c[0] + beta * c[1]).collect();
+        half.fft(&mut folded);
+        assert_eq!(domain.fri_fold(&evals, beta), folded);
+    }
+
+    #[test]
+    fn twiddle_factors_match_the_domain() {
+        let domain = Domain::new(4);
+        assert_eq!(twiddle_factors(4), domain.twiddles);
+        assert_eq!(domain.omega().pow_vartime([16]), Scalar::ONE);
+        assert_eq!(domain.omega().pow_vartime([8]), -Scalar::ONE);
+    }
+}
@@ -0,0 +1,71 @@
+//! Scalar field helpers.
+
+use blst::{
+    blst_fr, blst_fr_from_uint64, blst_scalar, blst_scalar_from_be_bytes,
+    blst_scalar_from_le_bytes, blst_uint64_from_fr,
+};
+use blstrs::Scalar;
+use ff::Field;
+
+/// Montgomery's batch inversion: inverts every (non-zero) element of `v` in
+/// place using a single field inversion and 3(n-1) multiplications.
+pub fn batch_invert(v: &mut [Scalar]) {
+    let mut prefix = Vec::with_capacity(v.len());
+    let mut acc = Scalar::ONE;
+    for x in v.iter() {
+        prefix.push(acc);
+        acc *= x;
+    }
+
+    let mut inv = acc.invert().unwrap();
+    for (x, p) in v.iter_mut().zip(prefix).rev() {
+        let x_inv = inv * p;
+        inv *= *x;
+        *x = x_inv;
+    }
+}
+
+/// `[1, x, x^2, ..., x^(n-1)]`, by a running product: one multiplication per
+/// element.
+pub fn powers(x: Scalar, n: usize) -> Vec<Scalar> {
+    let mut acc = Scalar::ONE;
+    (0..n)
+        .map(|_| {
+            let current = acc;
+            acc *= x;
+            current
+        })
+        .collect()
+}
+
+/// Reduces a little-endian integer of up to 512 bits mod r, e.g. a 64-byte hash
+/// output.
+pub fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar {
+    let mut s = blst_scalar::default();
+    unsafe { blst_scalar_from_le_bytes(&mut s, bytes.as_ptr(), bytes.len()) };
+    s.try_into().expect("reduced mod r")
+}
+
+/// Reduces a big-endian integer of any length mod r.
+pub fn from_be_bytes_wide(bytes: &[u8]) -> Scalar {
+    let mut s = blst_scalar::default();
+    unsafe { blst_scalar_from_be_bytes(&mut s, bytes.as_ptr(), bytes.len()) };
+    s.try_into().expect("reduced mod r")
+}
+
+/// Converts the little-endian limbs of an integer below r into Montgomery
+/// form, the internal representation: one multiplication by `R^2`.
+pub fn to_montgomery(limbs: &[u64; 4]) -> Scalar {
+    let mut out = blst_fr::default();
+    unsafe { blst_fr_from_uint64(&mut out, limbs.as_ptr()) };
+    out.into()
+}
+
+/// Converts a scalar out of Montgomery form into the little-endian limbs of
+/// its integer value: one Montgomery reduction.
+pub fn from_montgomery(s: &Scalar) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    let s: blst_fr = (*s).into();
+    unsafe { blst_uint64_from_fr(limbs.as_mut_ptr(), &s) };
+    limbs
+}
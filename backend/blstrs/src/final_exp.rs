@@ -0,0 +1,90 @@
+//! The final exponentiation of the BLS12-381 pairing, split into its easy and
+//! hard parts.
+//!
+//! blstrs only exposes the whole of blst's `final_exp`; this is the same
+//! computation step by step, so that `hard_part(easy_part(f))` equals it.
+
+use blst::{blst_fp12, blst_fp12_cyclotomic_sqr};
+use blstrs::Fp12;
+use ff::Field;
+
+/// The easy part, `f^((p^6 - 1)(p^2 + 1))`, which maps a Miller loop output
+/// into the cyclotomic subgroup. `f` must be nonzero.
+pub fn easy_part(f: &Fp12) -> Fp12 {
+    let mut t = *f;
+    t.conjugate();
+    t *= f.invert().unwrap();
+    let mut t2 = t;
+    t2.frobenius_map(2);
+    t * t2
+}
+
+/// The hard part, `f^(3(p^4 - p^2 + 1)/r)`, for `f` in the cyclotomic
+/// subgroup, i.e. an output of [`easy_part`].
+pub fn hard_part(f: &Fp12) -> Fp12 {
+    let y0 = cyclotomic_square(f);
+    let mut y1 = raise_to_z(&y0);
+    let y2 = raise_to_z_div_by_2(&y1);
+    y1 *= conjugate(f);
+    y1 = conjugate(&y1) * y2;
+    let mut y2 = raise_to_z(&y1);
+    let mut y3 = raise_to_z(&y2);
+    y3 *= conjugate(&y1);
+    y1.frobenius_map(3);
+    y2.frobenius_map(2);
+    y1 *= y2;
+    let y2 = raise_to_z(&y3) * y0 * f;
+    y1 *= y2;
+    y3.frobenius_map(1);
+    y1 * y3
+}
+
+fn conjugate(f: &Fp12) -> Fp12 {
+    let mut f = *f;
+    f.conjugate();
+    f
+}
+
+/// Squaring in the cyclotomic subgroup, cheaper than a generic `square`.
+fn cyclotomic_square(f: &Fp12) -> Fp12 {
+    let mut out = blst_fp12::default();
+    let f: blst_fp12 = (*f).into();
+    unsafe { blst_fp12_cyclotomic_sqr(&mut out, &f) };
+    out.into()
+}
+
+/// `f^(z/2)`, `z = -0xd201000000010000` the BLS parameter, along the addition
+/// chain of blst.
+fn raise_to_z_div_by_2(f: &Fp12) -> Fp12 {
+    let mut acc = cyclotomic_square(f);
+    for n in [2, 3, 9, 32, 15] {
+        acc *= f;
+        for _ in 0..n {
+            acc = cyclotomic_square(&acc);
+        }
+    }
+    // z is negative
+    conjugate(&acc)
+}
+
+fn raise_to_z(f: &Fp12) -> Fp12 {
+    cyclotomic_square(&raise_to_z_div_by_2(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blst::blst_final_exp;
+    use zkalc_common::config;
+
+    #[test]
+    fn matches_blst_final_exp() {
+        let mut rng = config::rng();
+        for _ in 0..4 {
+            let f = Fp12::random(&mut rng);
+            let mut expected = blst_fp12::default();
+            unsafe { blst_final_exp(&mut expected, &f.into()) };
+            assert_eq!(hard_part(&easy_part(&f)), expected.into());
+        }
+    }
+}
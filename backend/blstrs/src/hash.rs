@@ -0,0 +1,94 @@
+//! Hashing to the scalar field, following the hash-to-curve specification
+//! (RFC 9380) with blst's own `expand_message_xmd`, and the specification's
+//! nonuniform encodings to the curve.
+
+use blst::{blst_encode_to_g1, blst_encode_to_g2, blst_expand_message_xmd};
+use blstrs::{G1Projective, G2Projective, Scalar};
+use group::Group;
+
+use crate::field::from_be_bytes_wide;
+
+/// Bytes expanded per field element: `ceil((ceil(log2(r)) + 128) / 8)`, so that
+/// the reduction mod r is statistically uniform.
+const L: usize = 48;
+
+/// `expand_message_xmd` with SHA-256, producing `len` bytes.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; len];
+    unsafe {
+        blst_expand_message_xmd(
+            out.as_mut_ptr(),
+            out.len(),
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+        );
+    }
+    out
+}
+
+/// Hashes `msg` to `count` scalars: expands it to `count * L` bytes and reduces
+/// each `L`-byte chunk mod r.
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Scalar> {
+    expand_message_xmd(msg, dst, count * L)
+        .chunks(L)
+        .map(from_be_bytes_wide)
+        .collect()
+}
+
+/// `encode_to_curve` to G1: one SSWU map of one field element instead of the
+/// two of blstrs' `hash_to_curve`, and no addition. The output is not uniform,
+/// which protocols have to allow for.
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> G1Projective {
+    let mut out = G1Projective::identity();
+    unsafe {
+        blst_encode_to_g1(
+            out.as_mut(),
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            [].as_ptr(),
+            0,
+        );
+    }
+    out
+}
+
+/// `encode_to_curve` to G2, see [`encode_to_g1`].
+pub fn encode_to_g2(msg: &[u8], dst: &[u8]) -> G2Projective {
+    let mut out = G2Projective::identity();
+    unsafe {
+        blst_encode_to_g2(
+            out.as_mut(),
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            [].as_ptr(),
+            0,
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use group::prime::PrimeCurveAffine;
+    use group::Curve;
+
+    #[test]
+    fn encodings_land_in_the_subgroups() {
+        let msg = b"zkalc";
+        let p = encode_to_g1(msg, b"BLS12381G1_XMD:SHA-256_SSWU_NU_").to_affine();
+        let q = encode_to_g2(msg, b"BLS12381G2_XMD:SHA-256_SSWU_NU_").to_affine();
+        assert!(bool::from(p.is_torsion_free() & !p.is_identity()));
+        assert!(bool::from(q.is_torsion_free() & !q.is_identity()));
+        assert_ne!(
+            p,
+            G1Projective::hash_to_curve(msg, b"BLS12381G1_XMD:SHA-256_SSWU_NU_", &[]).to_affine()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! The inner-product argument of Bulletproofs in G1, the recursive core of
+//! its range proofs.
+//!
+//! The prover halves the vectors `log2(n)` times, sending two points per
+//! round; the verifier folds all rounds into a single MSM. The Fiat–Shamir
+//! challenges are taken as inputs, since hashing the transcript does not
+//! affect the group work that is benchmarked.
+
+use blstrs::{G1Projective, Scalar};
+use ff::Field;
+use group::Group;
+use rand::RngCore;
+use zkalc_common::msm::msm;
+
+use crate::curve::Bls12_381;
+
+/// The commitment key: independent generators for both vectors and for
+/// their inner product.
+pub struct Generators {
+    pub g: Vec<G1Projective>,
+    pub h: Vec<G1Projective>,
+    pub u: G1Projective,
+}
+
+/// The points sent in every round and the two final scalars.
+pub struct Proof {
+    pub l: Vec<G1Projective>,
+    pub r: Vec<G1Projective>,
+    pub a: Scalar,
+    pub b: Scalar,
+}
+
+fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+    a.iter().zip(b).map(|(a, b)| *a * b).sum()
+}
+
+/// `<a, g> + <b, h> + <a, b> u`, as one MSM.
+fn commit_with(
+    g: &[G1Projective],
+    h: &[G1Projective],
+    u: &G1Projective,
+    a: &[Scalar],
+    b: &[Scalar],
+) -> G1Projective {
+    let bases: Vec<_> = g.iter().chain(h).chain([u]).copied().collect();
+    let scalars: Vec<_> = a
+        .iter()
+        .chain(b)
+        .copied()
+        .chain([inner_product(a, b)])
+        .collect();
+    msm::<Bls12_381>(&bases, &scalars).unwrap()
+}
+
+impl Generators {
+    /// Random generators for vectors of `size` elements, a power of two.
+    pub fn random(size: usize, mut rng: impl RngCore) -> Self {
+        assert!(
+            size.is_power_of_two(),
+            "the vector size must be a power of two"
+        );
+        Generators {
+            g: (0..size).map(|_| G1Projective::random(&mut rng)).collect(),
+            h: (0..size).map(|_| G1Projective::random(&mut rng)).collect(),
+            u: G1Projective::random(&mut rng),
+        }
+    }
+
+    /// `<a, G> + <b, H> + <a, b> U`, the statement that the proof opens.
+    pub fn commit(&self, a: &[Scalar], b: &[Scalar]) -> G1Projective {
+        commit_with(&self.g, &self.h, &self.u, a, b)
+    }
+
+    /// Proves knowledge of `a` and `b` behind [`commit`](Self::commit), with
+    /// one non-zero challenge per round.
+    pub fn prove(&self, a: &[Scalar], b: &[Scalar], challenges: &[Scalar]) -> Proof {
+        assert_eq!(
+            1 << challenges.len(),
+            self.g.len(),
+            "one challenge per round"
+        );
+
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        let (mut g, mut h) = (self.g.clone(), self.h.clone());
+        let (mut l, mut r) = (Vec::new(), Vec::new());
+        for x in challenges {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+            let (h_lo, h_hi) = h.split_at(half);
+
+            l.push(commit_with(g_hi, h_lo, &self.u, a_lo, b_hi));
+            r.push(commit_with(g_lo, h_hi, &self.u, a_hi, b_lo));
+
+            let x_inv = x.invert().unwrap();
+            let fold = |lo: &[Scalar], hi: &[Scalar], x_lo: Scalar, x_hi: Scalar| -> Vec<_> {
+                lo.iter()
+                    .zip(hi)
+                    .map(|(lo, hi)| *lo * x_lo + *hi * x_hi)
+                    .collect()
+            };
+            let fold_points =
+                |lo: &[G1Projective], hi: &[G1Projective], x_lo: Scalar, x_hi: Scalar| -> Vec<_> {
+                    lo.iter()
+                        .zip(hi)
+                        .map(|(lo, hi)| lo * x_lo + hi * x_hi)
+                        .collect()
+                };
+            let next_a = fold(a_lo, a_hi, *x, x_inv);
+            let next_b = fold(b_lo, b_hi, x_inv, *x);
+            let next_g = fold_points(g_lo, g_hi, x_inv, *x);
+            let next_h = fold_points(h_lo, h_hi, *x, x_inv);
+            (a, b, g, h) = (next_a, next_b, next_g, next_h);
+        }
+
+        Proof {
+            l,
+            r,
+            a: a[0],
+            b: b[0],
+        }
+    }
+
+    /// Checks `proof` against `commitment` with a single MSM over all
+    /// generators and round points.
+    pub fn verify(&self, commitment: &G1Projective, proof: &Proof, challenges: &[Scalar]) -> bool {
+        let rounds = challenges.len();
+        assert_eq!(1 << rounds, self.g.len(), "one challenge per round");
+        if proof.l.len() != rounds || proof.r.len() != rounds {
+            return false;
+        }
+
+        // The folded G is <s, G> for s_i = prod_j x_j^(+-1), the sign given by
+        // whether i is in the upper half in round j; the folded H uses 1/s_i.
+        let inverses: Vec<_> = challenges.iter().map(|x| x.invert().unwrap()).collect();
+        let mut s = vec![inverses.iter().product::<Scalar>()];
+        let mut s_inv = vec![challenges.iter().product::<Scalar>()];
+        for i in 1..self.g.len() {
+            let bit = usize::BITS - 1 - i.leading_zeros();
+            let round = rounds - 1 - bit as usize;
+            let rest = i - (1 << bit);
+            s.push(s[rest] * challenges[round].square());
+            s_inv.push(s_inv[rest] * inverses[round].square());
+        }
+
+        // <a s, G> + <b / s, H> + a b U - sum(x^2 L + x^-2 R) = P
+        let bases: Vec<_> = self
+            .g
+            .iter()
+            .chain(&self.h)
+            .chain([&self.u])
+            .chain(&proof.l)
+            .chain(&proof.r)
+            .copied()
+            .collect();
+        let scalars: Vec<_> = s
+            .iter()
+            .map(|s| proof.a * s)
+            .chain(s_inv.iter().map(|s| proof.b * s))
+            .chain([proof.a * proof.b])
+            .chain(challenges.iter().map(|x| -x.square()))
+            .chain(inverses.iter().map(|x| -x.square()))
+            .collect();
+        msm::<Bls12_381>(&bases, &scalars).unwrap() == *commitment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkalc_common::config;
+
+    struct Instance {
+        generators: Generators,
+        commitment: G1Projective,
+        proof: Proof,
+        challenges: Vec<Scalar>,
+    }
+
+    fn prove(logsize: usize) -> Instance {
+        let mut rng = config::rng();
+        let size = 1 << logsize;
+        let generators = Generators::random(size, &mut rng);
+        let a: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let b: Vec<_> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+        let challenges: Vec<_> = (0..logsize).map(|_| Scalar::random(&mut rng)).collect();
+        Instance {
+            commitment: generators.commit(&a, &b),
+            proof: generators.prove(&a, &b, &challenges),
+            generators,
+            challenges,
+        }
+    }
+
+    #[test]
+    fn proofs_verify() {
+        for logsize in 0..=4 {
+            let ipa = prove(logsize);
+            assert_eq!(ipa.proof.l.len(), logsize);
+            assert!(ipa
+                .generators
+                .verify(&ipa.commitment, &ipa.proof, &ipa.challenges));
+        }
+    }
+
+    #[test]
+    fn tampered_proofs_are_rejected() {
+        let Instance {
+            generators,
+            commitment,
+            mut proof,
+            challenges,
+        } = prove(3);
+        let wrong_commitment = commitment + generators.u;
+        assert!(!generators.verify(&wrong_commitment, &proof, &challenges));
+
+        proof.a += Scalar::ONE;
+        assert!(!generators.verify(&commitment, &proof, &challenges));
+        proof.a -= Scalar::ONE;
+
+        proof.l[1] += generators.u;
+        assert!(!generators.verify(&commitment, &proof, &challenges));
+        proof.l[1] -= generators.u;
+
+        proof.r.pop();
+        assert!(!generators.verify(&commitment, &proof, &challenges));
+    }
+}
@@ -0,0 +1,152 @@
+//! KZG polynomial commitments in G1.
+
+use blstrs::{G1Projective, Scalar};
+use ff::Field;
+use group::Group;
+use rand::RngCore;
+use zkalc_common::curve::Curve;
+
+use crate::curve::Bls12_381;
+use crate::poly;
+
+/// The G1 part of a structured reference string.
+///
+/// A real SRS holds `[tau^i] G` for a secret `tau`. Its structure does not
+/// affect the cost of committing, so the benchmarks use random points, which
+/// are much faster to generate.
+pub struct Srs {
+    pub g1: Vec<G1Projective>,
+}
+
+impl Srs {
+    pub fn random(size: usize, mut rng: impl RngCore) -> Self {
+        Srs {
+            g1: (0..size).map(|_| G1Projective::random(&mut rng)).collect(),
+        }
+    }
+
+    /// Commits to the polynomial with coefficients `coeffs`.
+    pub fn commit(&self, coeffs: &[Scalar]) -> G1Projective {
+        assert!(
+            coeffs.len() <= self.g1.len(),
+            "SRS too small for polynomial"
+        );
+        Bls12_381::msm_g1(&self.g1[..coeffs.len()], coeffs)
+    }
+
+    /// Opens the polynomial at `z`: returns its evaluation and a commitment to
+    /// the witness `(p(X) - p(z)) / (X - z)`.
+    pub fn open(&self, coeffs: &[Scalar], z: &Scalar) -> (Scalar, G1Projective) {
+        let witness = poly::divide_by_linear(coeffs, z);
+        (poly::evaluate(coeffs, z), self.commit(&witness))
+    }
+
+    /// Opens several polynomials at the same `z` with a single proof: returns
+    /// their evaluations and the witness of `sum gamma^i p_i(X)`, which the
+    /// verifier checks against the same combination of the commitments and
+    /// the evaluations.
+    pub fn batch_open(
+        &self,
+        polys: &[Vec<Scalar>],
+        z: &Scalar,
+        gamma: &Scalar,
+    ) -> (Vec<Scalar>, G1Projective) {
+        let evals = polys.iter().map(|p| poly::evaluate(p, z)).collect();
+        let witness = poly::divide_by_linear(&combine(polys, gamma), z);
+        (evals, self.commit(&witness))
+    }
+}
+
+/// `sum gamma^i p_i(X)`, as long as the longest of the `polys`.
+fn combine(polys: &[Vec<Scalar>], gamma: &Scalar) -> Vec<Scalar> {
+    let len = polys.iter().map(Vec::len).max().unwrap_or(0);
+    let mut combined = vec![Scalar::ZERO; len];
+    let mut power = Scalar::ONE;
+    for p in polys {
+        for (c, x) in combined.iter_mut().zip(p) {
+            *c += power * x;
+        }
+        power *= gamma;
+    }
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::powers;
+    use zkalc_common::config;
+
+    /// `witness * (X - z) + eval`.
+    fn undo_division(witness: &[Scalar], z: &Scalar, eval: &Scalar) -> Vec<Scalar> {
+        let mut p = vec![Scalar::ZERO; witness.len() + 1];
+        for (i, w) in witness.iter().enumerate() {
+            p[i + 1] += w;
+            p[i] -= w * z;
+        }
+        p[0] += eval;
+        p
+    }
+
+    /// An SRS for a known `tau`, against which commitments can be checked
+    /// without a pairing.
+    fn srs_with_tau(size: usize, tau: Scalar) -> Srs {
+        let g = G1Projective::generator();
+        Srs {
+            g1: powers(tau, size).iter().map(|t| g * t).collect(),
+        }
+    }
+
+    #[test]
+    fn open_divides_out_the_evaluation() {
+        let mut rng = config::rng();
+        let (z, tau) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+        let p: Vec<_> = (0..9).map(|_| Scalar::random(&mut rng)).collect();
+        let srs = srs_with_tau(p.len(), tau);
+
+        let (eval, proof) = srs.open(&p, &z);
+        assert_eq!(eval, poly::evaluate(&p, &z));
+        let witness = poly::divide_by_linear(&p, &z);
+        assert_eq!(undo_division(&witness, &z, &eval), p);
+        assert_eq!(proof, srs.commit(&witness));
+        let g = G1Projective::generator();
+        assert_eq!(proof * (tau - z) + g * eval, srs.commit(&p));
+    }
+
+    #[test]
+    fn batch_open_divides_out_the_combined_evaluation() {
+        let mut rng = config::rng();
+        let [z, gamma, tau] = [(); 3].map(|_| Scalar::random(&mut rng));
+        let polys: Vec<Vec<_>> = [9, 4, 7]
+            .iter()
+            .map(|&len| (0..len).map(|_| Scalar::random(&mut rng)).collect())
+            .collect();
+        let srs = srs_with_tau(9, tau);
+
+        let (evals, proof) = srs.batch_open(&polys, &z, &gamma);
+        let combined = combine(&polys, &gamma);
+        let gammas = powers(gamma, polys.len());
+        for (i, c) in combined.iter().enumerate() {
+            let expected: Scalar = polys
+                .iter()
+                .zip(&gammas)
+                .map(|(p, g)| p.get(i).map_or(Scalar::ZERO, |x| x * g))
+                .sum();
+            assert_eq!(*c, expected);
+        }
+        let eval: Scalar = evals.iter().zip(&gammas).map(|(e, g)| e * g).sum();
+        assert_eq!(eval, poly::evaluate(&combined, &z));
+        let witness = poly::divide_by_linear(&combined, &z);
+        assert_eq!(undo_division(&witness, &z, &eval), combined);
+        assert_eq!(proof, srs.commit(&witness));
+        let commitment: G1Projective = polys
+            .iter()
+            .zip(&gammas)
+            .map(|(p, g)| srs.commit(p) * g)
+            .sum();
+        assert_eq!(
+            proof * (tau - z) + G1Projective::generator() * eval,
+            commitment
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Support code shared by the blstrs benchmarks.
+//!
+//! The `jemalloc` and `mimalloc` features replace the global allocator of
+//! everything linking this crate, which MSMs are sensitive to.
+
+pub mod affine;
+pub mod cofactor;
+pub mod curve;
+pub mod fft;
+pub mod field;
+pub mod final_exp;
+pub mod hash;
+pub mod ipa;
+pub mod kzg;
+pub mod merkle;
+pub mod poly;
+pub mod transcript;
+
+#[cfg(all(feature = "jemalloc", feature = "mimalloc"))]
+compile_error!("features `jemalloc` and `mimalloc` are mutually exclusive");
+
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+#[cfg(feature = "mimalloc")]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
@@ -0,0 +1,79 @@
+//! Binary Merkle trees over a pluggable two-to-one hash.
+
+use blstrs::Scalar;
+use generic_array::typenum::U2;
+use neptune::poseidon::{Poseidon, PoseidonConstants};
+use sha2::{Digest, Sha256};
+
+/// A two-to-one compression function for the inner nodes of a tree.
+pub trait MerkleHash {
+    type Digest: Clone;
+
+    fn hash_pair(&self, left: &Self::Digest, right: &Self::Digest) -> Self::Digest;
+}
+
+/// Poseidon with width 3 over the scalar field, an arithmetization-friendly hash.
+pub struct PoseidonHash {
+    constants: PoseidonConstants<Scalar, U2>,
+}
+
+impl Default for PoseidonHash {
+    fn default() -> Self {
+        PoseidonHash {
+            constants: PoseidonConstants::new(),
+        }
+    }
+}
+
+impl MerkleHash for PoseidonHash {
+    type Digest = Scalar;
+
+    fn hash_pair(&self, left: &Scalar, right: &Scalar) -> Scalar {
+        Poseidon::new_with_preimage(&[*left, *right], &self.constants).hash()
+    }
+}
+
+/// SHA-256 of the concatenated children.
+pub struct Sha256Hash;
+
+impl MerkleHash for Sha256Hash {
+    type Digest = [u8; 32];
+
+    fn hash_pair(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(left)
+            .chain_update(right)
+            .finalize()
+            .into()
+    }
+}
+
+/// The root of the tree over `leaves`, whose number must be a power of two.
+pub fn root<H: MerkleHash>(hash: &H, leaves: &[H::Digest]) -> H::Digest {
+    cap(hash, leaves, 0).pop().unwrap()
+}
+
+/// The `2^cap_height` nodes of the tree over `leaves` at height `cap_height`
+/// below the root, at which FRI commitments stop hashing. A cap of height 0
+/// is the root.
+pub fn cap<H: MerkleHash>(hash: &H, leaves: &[H::Digest], cap_height: u32) -> Vec<H::Digest> {
+    assert!(
+        leaves.len().is_power_of_two(),
+        "a binary Merkle tree needs a power-of-two number of leaves, not {}",
+        leaves.len()
+    );
+    assert!(
+        leaves.len() >= 1 << cap_height,
+        "a tree of {} leaves has no cap of height {}",
+        leaves.len(),
+        cap_height
+    );
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 << cap_height {
+        layer = layer
+            .chunks_exact(2)
+            .map(|pair| hash.hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    layer
+}
@@ -0,0 +1,101 @@
+//! Dense univariate polynomials over the BLS12-381 scalar field, stored as
+//! coefficient vectors in increasing degree order.
+
+use blstrs::Scalar;
+use ff::Field;
+
+use crate::fft::Domain;
+use crate::field::batch_invert;
+
+/// Evaluates `coeffs` at `x` with Horner's rule.
+pub fn evaluate(coeffs: &[Scalar], x: &Scalar) -> Scalar {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Scalar::ZERO, |acc, coeff| acc * x + coeff)
+}
+
+/// Divides `coeffs` by `X - z`, returning the quotient and discarding the
+/// remainder, which is the evaluation at `z`.
+pub fn divide_by_linear(coeffs: &[Scalar], z: &Scalar) -> Vec<Scalar> {
+    let mut quotient = vec![Scalar::ZERO; coeffs.len().saturating_sub(1)];
+    let mut acc = Scalar::ZERO;
+    for i in (1..coeffs.len()).rev() {
+        acc = coeffs[i] + *z * acc;
+        quotient[i - 1] = acc;
+    }
+    quotient
+}
+
+/// Multiplies `a` and `b` by FFT convolution over `domain`, whose size must be
+/// at least the number of coefficients of the product.
+pub fn mul(domain: &Domain, a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let product_len = a.len() + b.len() - 1;
+    assert!(domain.size() >= product_len, "domain too small for product");
+
+    let mut a_evals = a.to_vec();
+    a_evals.resize(domain.size(), Scalar::ZERO);
+    let mut b_evals = b.to_vec();
+    b_evals.resize(domain.size(), Scalar::ZERO);
+
+    domain.fft(&mut a_evals);
+    domain.fft(&mut b_evals);
+    for (x, y) in a_evals.iter_mut().zip(b_evals.iter()) {
+        *x *= y;
+    }
+    domain.ifft(&mut a_evals);
+
+    a_evals.truncate(product_len);
+    a_evals
+}
+
+/// The barycentric weights `w_j = 1 / prod_{k != j} (x_j - x_k)` of the
+/// (distinct) nodes `xs`, using a single batched inversion.
+pub fn barycentric_weights(xs: &[Scalar]) -> Vec<Scalar> {
+    let mut weights: Vec<_> = xs
+        .iter()
+        .enumerate()
+        .map(|(j, x_j)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(k, _)| *k != j)
+                .fold(Scalar::ONE, |acc, (_, x_k)| acc * (x_j - x_k))
+        })
+        .collect();
+    batch_invert(&mut weights);
+    weights
+}
+
+/// Lagrange interpolation: the coefficients of the unique polynomial of
+/// degree `< n` through the `n` points `(xs[i], ys[i])`. This takes `O(n^2)`
+/// field operations.
+pub fn interpolate(xs: &[Scalar], ys: &[Scalar]) -> Vec<Scalar> {
+    assert_eq!(xs.len(), ys.len());
+    let n = xs.len();
+    let weights = barycentric_weights(xs);
+
+    // The vanishing polynomial of the nodes, M(X) = prod_i (X - x_i)
+    let mut vanishing = vec![Scalar::ONE];
+    for x in xs {
+        vanishing.push(Scalar::ZERO);
+        for i in (1..vanishing.len()).rev() {
+            vanishing[i] = vanishing[i - 1] - *x * vanishing[i];
+        }
+        vanishing[0] = -(*x * vanishing[0]);
+    }
+
+    // Accumulate y_j * w_j * M(X) / (X - x_j), dividing synthetically
+    let mut coeffs = vec![Scalar::ZERO; n];
+    for ((x, y), w) in xs.iter().zip(ys).zip(weights) {
+        let scale = *y * w;
+        let mut quotient = Scalar::ZERO;
+        for i in (1..=n).rev() {
+            quotient = vanishing[i] + *x * quotient;
+            coeffs[i - 1] += scale * quotient;
+        }
+    }
+    coeffs
+}
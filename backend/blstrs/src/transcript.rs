@@ -0,0 +1,43 @@
+//! A Fiat–Shamir transcript over BLAKE2b, laid out like halo2's: every message
+//! is hashed in behind a one-byte tag, and a challenge is the 64-byte digest of
+//! everything so far, reduced mod r.
+
+use blake2b_simd::{Params, State};
+use blstrs::{G1Affine, Scalar};
+use ff::PrimeField;
+
+use crate::field::from_bytes_wide;
+
+const POINT: u8 = 1;
+const SCALAR: u8 = 2;
+const CHALLENGE: u8 = 0;
+
+pub struct Transcript {
+    state: State,
+}
+
+impl Transcript {
+    /// An empty transcript, domain-separated by `personal` (at most 16 bytes).
+    pub fn new(personal: &[u8]) -> Self {
+        Transcript {
+            state: Params::new().hash_length(64).personal(personal).to_state(),
+        }
+    }
+
+    pub fn absorb_point(&mut self, p: &G1Affine) {
+        self.state.update(&[POINT]);
+        self.state.update(&p.to_compressed());
+    }
+
+    pub fn absorb_scalar(&mut self, s: &Scalar) {
+        self.state.update(&[SCALAR]);
+        self.state.update(&s.to_repr());
+    }
+
+    /// Derives a challenge from everything absorbed so far. The challenge is
+    /// itself part of the transcript, so the next one differs.
+    pub fn squeeze_challenge(&mut self) -> Scalar {
+        self.state.update(&[CHALLENGE]);
+        from_bytes_wide(self.state.finalize().as_array())
+    }
+}
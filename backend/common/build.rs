@@ -0,0 +1,8 @@
+// Passes the target triple on to `machine::MachineInfo`, as only build
+// scripts are told it.
+fn main() {
+    println!(
+        "cargo:rustc-env=ZKALC_TARGET={}",
+        std::env::var("TARGET").unwrap()
+    );
+}
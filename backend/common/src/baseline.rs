@@ -0,0 +1,136 @@
+//! Regression detection against the results of a previous run.
+
+use std::collections::HashMap;
+
+use crate::results::Results;
+
+/// The default slowdown, in percent, above which a benchmark has regressed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// A benchmark that got slower than the threshold allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub curve: String,
+    pub operation: String,
+    pub size: Option<u64>,
+    /// Median seconds in the baseline.
+    pub baseline: f64,
+    /// Median seconds in the current run.
+    pub current: f64,
+}
+
+impl Regression {
+    /// The slowdown in percent, or 0 against a zero baseline, which
+    /// [`regressions`] never reports.
+    pub fn percent(&self) -> f64 {
+        if self.baseline > 0.0 {
+            (self.current / self.baseline - 1.0) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Returns the benchmarks of `current` whose median is more than `threshold`
+/// percent slower than in `baseline`.
+///
+/// Benchmarks are matched by curve, operation and size; benchmarks that are not
+/// in the baseline are new and never count as regressions, and neither do
+/// benchmarks whose baseline median is zero, which give no slowdown.
+pub fn regressions(baseline: &Results, current: &Results, threshold: f64) -> Vec<Regression> {
+    let baseline: HashMap<_, _> = baseline
+        .results
+        .iter()
+        .map(|r| (r.key(), r.seconds.median))
+        .collect();
+
+    current
+        .results
+        .iter()
+        .filter_map(|r| {
+            let &before = baseline.get(&r.key()).filter(|&&before| before > 0.0)?;
+            let regression = Regression {
+                curve: r.curve.clone(),
+                operation: r.operation.clone(),
+                size: r.size,
+                baseline: before,
+                current: r.seconds.median,
+            };
+            (regression.percent() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::{BenchResult, Seconds};
+
+    fn result(operation: &str, size: Option<u64>, median: f64) -> BenchResult {
+        let seconds = Seconds {
+            mean: median,
+            median,
+            std_dev: 0.0,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        BenchResult::new("bls12_381".into(), operation.into(), size, seconds, 100)
+    }
+
+    #[test]
+    fn reports_slowdowns_above_the_threshold() {
+        let baseline = Results::new(vec![
+            result("pairing", None, 1e-3),
+            result("msm_G1", Some(1024), 2e-3),
+            result("msm_G1", Some(2048), 4e-3),
+        ]);
+        let current = Results::new(vec![
+            result("pairing", None, 1.05e-3),
+            result("msm_G1", Some(1024), 2.5e-3),
+            result("msm_G1", Some(2048), 3e-3),
+        ]);
+
+        let found = regressions(&baseline, &current, DEFAULT_THRESHOLD);
+        assert_eq!(
+            found,
+            vec![Regression {
+                curve: "bls12_381".into(),
+                operation: "msm_G1".into(),
+                size: Some(1024),
+                baseline: 2e-3,
+                current: 2.5e-3,
+            }]
+        );
+        assert!((found[0].percent() - 25.0).abs() < 1e-9);
+        // A tighter threshold also catches the 5% slowdown of the pairing
+        assert_eq!(regressions(&baseline, &current, 6.0).len(), 1);
+        assert_eq!(regressions(&baseline, &current, 4.0).len(), 2);
+    }
+
+    #[test]
+    fn new_benchmarks_are_not_regressions() {
+        let baseline = Results::new(vec![result("pairing", None, 1e-3)]);
+        let current = Results::new(vec![
+            result("pairing", None, 1e-3),
+            result("msm_G1", Some(1024), 2e-3),
+        ]);
+        assert_eq!(regressions(&baseline, &current, DEFAULT_THRESHOLD), vec![]);
+    }
+
+    #[test]
+    fn zero_baselines_are_not_regressions() {
+        let baseline = Results::new(vec![result("noop", None, 0.0)]);
+        let current = Results::new(vec![result("noop", None, 1e-9)]);
+        assert_eq!(regressions(&baseline, &current, DEFAULT_THRESHOLD), vec![]);
+
+        let regression = Regression {
+            curve: "bls12_381".into(),
+            operation: "noop".into(),
+            size: None,
+            baseline: 0.0,
+            current: 1e-9,
+        };
+        assert_eq!(regression.percent(), 0.0);
+    }
+}
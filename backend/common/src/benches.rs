@@ -0,0 +1,202 @@
+//! Benchmarks shared by all backends, generic over [`Curve`].
+//!
+//! The ids are `<curve>/noop`, `<curve>/add_ff`, `<curve>/mul_ff`, `<curve>/invert`,
+//! `<curve>/add_G1`, `<curve>/scalar_mul/G1`, `<curve>/msm/G1/<size>` and, for
+//! pairing-friendly curves, the same G2 operations plus `<curve>/pairing`.
+//!
+//! Inputs are always generated before the timed loop. Operations whose cost
+//! may depend on the input, such as inversion, cycle through an [`InputPool`].
+//!
+//! Operations that `ZKALC_ONLY` does not select (see [`config::selected`]) are
+//! skipped along with their setup.
+
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use crate::config;
+use crate::curve::{Curve, PairingCurve};
+use crate::msm::{msm, msm_g2, MsmError};
+
+/// Random inputs generated ahead of the timed loop.
+///
+/// Calling the RNG inside `b.iter` would add its cost to the measurement, so
+/// benches that want a fresh input per iteration fill a pool up front and cycle
+/// through it instead:
+///
+/// ```no_run
+/// use criterion::Criterion;
+/// use rand::Rng;
+/// use zkalc_common::benches::InputPool;
+/// use zkalc_common::config;
+///
+/// let mut c = Criterion::default();
+/// let mut rng = config::rng();
+/// let mut pool = InputPool::new(|| rng.gen::<u64>());
+/// c.bench_function("u64/leading_zeros", |b| {
+///     b.iter(|| pool.next_input().leading_zeros())
+/// });
+/// ```
+pub struct InputPool<T> {
+    inputs: Vec<T>,
+    index: usize,
+}
+
+impl<T> InputPool<T> {
+    /// Enough inputs to defeat branch prediction on input-dependent code, and
+    /// few enough to stay in cache.
+    pub const SIZE: usize = 1024;
+
+    pub fn new(mut generate: impl FnMut() -> T) -> Self {
+        InputPool {
+            inputs: (0..Self::SIZE).map(|_| generate()).collect(),
+            index: 0,
+        }
+    }
+
+    /// The next input, wrapping around at the end of the pool.
+    pub fn next_input(&mut self) -> &T {
+        let i = self.index;
+        self.index = (i + 1) % self.inputs.len();
+        &self.inputs[i]
+    }
+}
+
+/// Benchmarks scalar field addition, multiplication and inversion, after the
+/// `noop` that the cheapest operations are calibrated with (see
+/// [`crate::results::Results::subtract_overhead`]).
+pub fn bench_field_ops<C: Curve>(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group(C::NAME);
+    // Criterion's own per-iteration overhead, always measured as it needs no
+    // setup
+    group.bench_function("noop", |b| b.iter(|| black_box(())));
+    let lhs = C::rand_scalar(&mut rng);
+    let rhs = C::rand_scalar(&mut rng);
+    if config::selected("add_ff") {
+        group.bench_function("add_ff", |b| b.iter(|| black_box(lhs) + black_box(rhs)));
+    }
+    if config::selected("mul_ff") {
+        group.bench_function("mul_ff", |b| b.iter(|| black_box(lhs) * black_box(rhs)));
+    }
+    if config::selected("invert") {
+        let mut pool = InputPool::new(|| C::rand_scalar(&mut rng));
+        group.bench_function("invert", |b| {
+            b.iter(|| C::invert(pool.next_input()).unwrap())
+        });
+    }
+    group.finish()
+}
+
+/// Benchmarks G1 addition, scalar multiplication and MSM.
+pub fn bench_group_ops<C: Curve>(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group(C::NAME);
+    let lhs = C::rand_g1(&mut rng);
+    let rhs = C::rand_g1(&mut rng);
+    let scalar = C::rand_scalar(&mut rng);
+    if config::selected("add_G1") {
+        group.bench_function("add_G1", |b| b.iter(|| black_box(lhs) + black_box(rhs)));
+    }
+    if config::selected("scalar_mul") {
+        group.bench_function("scalar_mul/G1", |b| {
+            b.iter(|| black_box(lhs) * black_box(scalar))
+        });
+    }
+    group.finish();
+
+    if config::selected("msm") {
+        bench_msm::<C, _, _>(c, "G1", C::rand_g1, C::g1_base, msm::<C>);
+    }
+}
+
+/// Benchmarks G2 addition, scalar multiplication and MSM, and the pairing.
+pub fn bench_pairing_ops<C: PairingCurve>(c: &mut Criterion) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group(C::NAME);
+    let lhs = C::rand_g2(&mut rng);
+    let rhs = C::rand_g2(&mut rng);
+    let scalar = C::rand_scalar(&mut rng);
+    if config::selected("add_G2") {
+        group.bench_function("add_G2", |b| b.iter(|| black_box(lhs) + black_box(rhs)));
+    }
+    if config::selected("scalar_mul") {
+        group.bench_function("scalar_mul/G2", |b| {
+            b.iter(|| black_box(lhs) * black_box(scalar))
+        });
+    }
+
+    if config::selected("pairing") {
+        let a = C::g1_to_affine(&C::rand_g1(&mut rng));
+        let b = C::g2_to_affine(&C::rand_g2(&mut rng));
+        group.bench_function("pairing", |r| {
+            r.iter(|| C::pairing(black_box(&a), black_box(&b)))
+        });
+    }
+    group.finish();
+
+    if config::selected("msm") {
+        bench_msm::<C, _, _>(c, "G2", C::rand_g2, C::g2_base, msm_g2::<C>);
+    }
+}
+
+/// The MSM sweep over [`config::msm_logsizes_up_to`] the curve's maximum.
+fn bench_msm<C: Curve, G, B>(
+    c: &mut Criterion,
+    name: &str,
+    rand: impl Fn(&mut rand::rngs::StdRng) -> G,
+    to_base: impl Fn(&G) -> B,
+    msm: impl Fn(&[B], &[C::Scalar]) -> Result<G, MsmError>,
+) {
+    let mut rng = config::rng();
+    let mut group = c.benchmark_group(format!("{}/msm", C::NAME));
+    for logsize in config::msm_logsizes_up_to(C::MSM_MAX_LOGSIZE) {
+        group.sample_size(config::sample_size(logsize));
+
+        let size = 1 << logsize;
+        let scalars: Vec<_> = (0..size).map(|_| C::rand_scalar(&mut rng)).collect();
+        let bases: Vec<_> = (0..size).map(|_| to_base(&rand(&mut rng))).collect();
+        group.bench_with_input(BenchmarkId::new(name, size), &size, |b, _| {
+            b.iter(|| msm(&bases, &scalars).unwrap())
+        });
+    }
+    group.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::curve::mock::{Mock, Zp};
+
+    #[test]
+    fn mock_curve_is_consistent() {
+        let mut rng = config::rng();
+        let x = Mock::rand_scalar(&mut rng);
+        assert_eq!(x * Mock::invert(&x).unwrap(), Zp(1));
+
+        let a = Mock::rand_g1(&mut rng);
+        let b = Mock::rand_g2(&mut rng);
+        assert_eq!(
+            Mock::msm_g1(&[Mock::g1_base(&a), Mock::g1_base(&a)], &[x, x]),
+            a * (x + x)
+        );
+        assert_eq!(Mock::pairing(&(a * x), &b), Mock::pairing(&a, &(b * x)));
+    }
+
+    #[test]
+    fn shared_benches_run_on_mock_curve() {
+        // Keep the MSM sweep and the sampling as short as possible
+        std::env::set_var("ZKALC_MSM_MIN", "1");
+        std::env::set_var("ZKALC_MSM_MAX", "2");
+        std::env::set_var("ZKALC_SAMPLES", "10");
+        let mut c = Criterion::default()
+            .warm_up_time(Duration::from_millis(1))
+            .measurement_time(Duration::from_millis(1))
+            .nresamples(100)
+            .without_plots();
+
+        bench_field_ops::<Mock>(&mut c);
+        bench_group_ops::<Mock>(&mut c);
+        bench_pairing_ops::<Mock>(&mut c);
+    }
+}
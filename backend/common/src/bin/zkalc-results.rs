@@ -0,0 +1,253 @@
+//! Post-processes benchmark output into zkalc's results schema (see
+//! `zkalc_common::results`).
+//!
+//! Usage:
+//!
+//!     zkalc-results collect [CRITERION_JSON | CRITERION_DIR] [--allocator NAME] [--output RESULTS_JSON]
+//!     zkalc-results report LEFT_JSON RIGHT_JSON [--curves LEFT:RIGHT] [--markdown PATH]
+//!     zkalc-results compare-baseline BASELINE_JSON CURRENT_JSON [--threshold PERCENT]
+//!     zkalc-results to-csv [RESULTS_JSON] [--output CSV]
+//!     zkalc-results merge MACHINE=RESULTS_JSON... [--output RESULTS_JSON]
+//!     zkalc-results migrate [RESULTS_JSON] [--output RESULTS_JSON]
+//!
+//! `collect` reads the output of `cargo criterion --message-format=json` from
+//! the given file (or stdin) and writes the results to `--output` (or stdout).
+//! Given a directory instead, such as `target/criterion`, it collects the
+//! benchmarks that `cargo bench` saved there. The results describe the
+//! machine that `collect` runs on, which should be the one that ran them,
+//! and `--allocator` names the global allocator the benchmarks were built
+//! with, e.g. `jemalloc`.
+//!
+//! `report` compares two backends' results files operation by operation and
+//! prints the ratio of their medians, optionally also writing the table as
+//! Markdown. By default benchmarks are aligned by curve, operation and size;
+//! `--curves bn254:bls12_381` instead compares one curve of the left file with
+//! another curve of the right file.
+//!
+//! `compare-baseline` exits with a non-zero status if any benchmark of the
+//! current run is more than `--threshold` percent (10 by default) slower than
+//! in the baseline, and prints the offending benchmarks.
+//!
+//! `to-csv` converts a results file (or stdin) to CSV for spreadsheets.
+//!
+//! `merge` combines the results files of several machines, each given with
+//! its machine's label, e.g. `laptop=blstrs.json`, into a single file whose
+//! results carry the label. Every label may only be used once.
+//!
+//! `migrate` rewrites a results file of an older schema version in the current
+//! one. Every other command also reads older files, upgrading them on the fly.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+use std::process::ExitCode;
+
+use zkalc_common::baseline::{self, DEFAULT_THRESHOLD};
+use zkalc_common::machine::MachineInfo;
+use zkalc_common::report::{format_seconds, Report};
+use zkalc_common::results::Results;
+
+const USAGE: &str = "usage:
+    zkalc-results collect [CRITERION_JSON | CRITERION_DIR] [--allocator NAME] [--output RESULTS_JSON]
+    zkalc-results report LEFT_JSON RIGHT_JSON [--curves LEFT:RIGHT] [--markdown PATH]
+    zkalc-results compare-baseline BASELINE_JSON CURRENT_JSON [--threshold PERCENT]
+    zkalc-results to-csv [RESULTS_JSON] [--output CSV]
+    zkalc-results merge MACHINE=RESULTS_JSON... [--output RESULTS_JSON]
+    zkalc-results migrate [RESULTS_JSON] [--output RESULTS_JSON]";
+
+type CmdResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Parses `[INPUT] [--output OUTPUT]`, where both default to the standard streams.
+fn input_output(args: &[String]) -> Result<(Option<&String>, Option<&String>), &'static str> {
+    let mut input = None;
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" | "-o" => output = Some(args.next().ok_or(USAGE)?),
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(USAGE),
+        }
+    }
+    Ok((input, output))
+}
+
+fn collect(args: &[String]) -> CmdResult {
+    let (allocator, args) = match args.iter().position(|arg| arg == "--allocator") {
+        Some(i) => {
+            let name = args.get(i + 1).ok_or(USAGE)?;
+            (Some(name.clone()), [&args[..i], &args[i + 2..]].concat())
+        }
+        None => (None, args.to_vec()),
+    };
+    let (input, output) = input_output(&args)?;
+    let mut results = match input {
+        Some(path) if Path::new(path).is_dir() => Results::from_criterion_dir(path)?,
+        Some(path) => Results::from_criterion_messages(BufReader::new(File::open(path)?))?,
+        None => Results::from_criterion_messages(io::stdin().lock())?,
+    };
+    // Results are collected on the machine that ran the benchmarks
+    results.machine = Some(MachineInfo {
+        allocator,
+        ..MachineInfo::detect()
+    });
+    match output {
+        Some(path) => results.to_writer(File::create(path)?)?,
+        None => results.to_writer(io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+fn to_csv(args: &[String]) -> CmdResult {
+    let (input, output) = input_output(args)?;
+    let results = match input {
+        Some(path) => Results::open(path)?,
+        None => Results::from_reader(io::stdin().lock())?,
+    };
+    match output {
+        Some(path) => results.to_csv(BufWriter::new(File::create(path)?))?,
+        None => results.to_csv(io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+fn migrate(args: &[String]) -> CmdResult {
+    let (input, output) = input_output(args)?;
+    let results = match input {
+        Some(path) => Results::open(path)?,
+        None => Results::from_reader(io::stdin().lock())?,
+    };
+    match output {
+        Some(path) => results.to_writer(File::create(path)?)?,
+        None => results.to_writer(io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+fn merge(args: &[String]) -> CmdResult {
+    let mut runs = Vec::new();
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" | "-o" => output = Some(args.next().ok_or(USAGE)?),
+            _ => {
+                let (machine, path) = arg.split_once('=').ok_or(USAGE)?;
+                runs.push((machine.to_string(), Results::open(path)?));
+            }
+        }
+    }
+    if runs.is_empty() {
+        return Err(USAGE.into());
+    }
+
+    let merged = Results::merge(runs)?;
+    match output {
+        Some(path) => merged.to_writer(File::create(path)?)?,
+        None => merged.to_writer(io::stdout().lock())?,
+    }
+    Ok(())
+}
+
+/// Names a backend after its results file, e.g. `blstrs` for `blstrs.json`.
+fn backend_name(path: &str) -> String {
+    Path::new(path).file_stem().map_or_else(
+        || path.to_string(),
+        |stem| stem.to_string_lossy().into_owned(),
+    )
+}
+
+fn report(args: &[String]) -> CmdResult {
+    let mut paths = Vec::new();
+    let mut curves = None;
+    let mut markdown = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--curves" => {
+                let value = args.next().ok_or(USAGE)?;
+                curves = Some(value.split_once(':').ok_or(USAGE)?);
+            }
+            "--markdown" => markdown = Some(args.next().ok_or(USAGE)?),
+            _ => paths.push(arg),
+        }
+    }
+    let [left, right] = paths[..] else {
+        return Err(USAGE.into());
+    };
+
+    let report = Report::new(
+        &backend_name(left),
+        &Results::open(left)?,
+        &backend_name(right),
+        &Results::open(right)?,
+        curves,
+    );
+    print!("{}", report.to_text());
+    if let Some(path) = markdown {
+        std::fs::write(path, report.to_markdown())?;
+    }
+    Ok(())
+}
+
+fn compare_baseline(args: &[String]) -> CmdResult {
+    let mut paths = Vec::new();
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threshold" => threshold = args.next().ok_or(USAGE)?.parse()?,
+            _ => paths.push(arg),
+        }
+    }
+    let [baseline, current] = paths[..] else {
+        return Err(USAGE.into());
+    };
+
+    let regressions = baseline::regressions(
+        &Results::open(baseline)?,
+        &Results::open(current)?,
+        threshold,
+    );
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    for r in &regressions {
+        let size = r.size.map(|s| format!("/{}", s)).unwrap_or_default();
+        println!(
+            "{}/{}{}: {} -> {} (+{:.1}%)",
+            r.curve,
+            r.operation,
+            size,
+            format_seconds(r.baseline),
+            format_seconds(r.current),
+            r.percent()
+        );
+    }
+    Err(format!(
+        "{} benchmarks regressed by more than {}%",
+        regressions.len(),
+        threshold
+    )
+    .into())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("collect") => collect(&args[1..]),
+        Some("report") => report(&args[1..]),
+        Some("compare-baseline") => compare_baseline(&args[1..]),
+        Some("to-csv") => to_csv(&args[1..]),
+        Some("merge") => merge(&args[1..]),
+        Some("migrate") => migrate(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("zkalc-results: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Benchmark parameters that can be overridden from the environment, so that a
+//! quick smoke run or a long high-precision run needs no source changes.
+//!
+//! Invalid values are reported on stderr and replaced by the defaults.
+
+use std::env;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Reads `name` from the environment, falling back to `default` if it is unset
+/// or does not parse.
+pub fn env_or<T: FromStr>(name: &str, default: T) -> T {
+    match env::var(name) {
+        Ok(value) => value.parse().unwrap_or_else(|_| {
+            eprintln!("zkalc: ignoring {}={:?}: not a valid value", name, value);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+/// A sweep of log2 sizes `min..=max`, with both ends read from the environment.
+fn log_range(
+    min_name: &str,
+    max_name: &str,
+    default: RangeInclusive<usize>,
+) -> RangeInclusive<usize> {
+    let min = env_or(min_name, *default.start());
+    let max = env_or(max_name, *default.end());
+    if min >= max {
+        eprintln!(
+            "zkalc: ignoring {}={} and {}={}: the minimum must be below the maximum",
+            min_name, min, max_name, max
+        );
+        return default;
+    }
+    min..=max
+}
+
+/// The default `ZKALC_MSM_MAX`.
+pub const MSM_MAX_LOGSIZE: usize = 21;
+
+/// The log2 sizes of the MSM sweeps, `ZKALC_MSM_MIN..=ZKALC_MSM_MAX`.
+pub fn msm_logsizes() -> RangeInclusive<usize> {
+    msm_logsizes_up_to(MSM_MAX_LOGSIZE)
+}
+
+/// [`msm_logsizes`] with another default for `ZKALC_MSM_MAX`, for curves
+/// whose MSMs are too slow to sweep up to the usual size.
+pub fn msm_logsizes_up_to(max: usize) -> RangeInclusive<usize> {
+    log_range("ZKALC_MSM_MIN", "ZKALC_MSM_MAX", 1..=max)
+}
+
+/// The number of terms per chunk of the chunked MSM, `ZKALC_MSM_CHUNK`, 2^16
+/// by default.
+pub fn msm_chunk() -> usize {
+    let default = 1 << 16;
+    let chunk = env_or("ZKALC_MSM_CHUNK", default);
+    if chunk == 0 {
+        eprintln!("zkalc: ignoring ZKALC_MSM_CHUNK=0: chunks must not be empty");
+        return default;
+    }
+    chunk
+}
+
+/// The log2 sizes of the pairing product sweep,
+/// `ZKALC_PAIRING_MIN..=ZKALC_PAIRING_MAX`.
+pub fn pairing_logsizes() -> RangeInclusive<usize> {
+    pairing_logsizes_up_to(10)
+}
+
+/// [`pairing_logsizes`] with another default for `ZKALC_PAIRING_MAX`, for
+/// backends whose published pairing product series goes further.
+pub fn pairing_logsizes_up_to(max: usize) -> RangeInclusive<usize> {
+    log_range("ZKALC_PAIRING_MIN", "ZKALC_PAIRING_MAX", 1..=max)
+}
+
+/// The default `ZKALC_QUADRATIC_MAX`.
+pub const QUADRATIC_MAX_LOGSIZE: usize = 14;
+
+/// `sweep` cut off at `ZKALC_QUADRATIC_MAX`, for operations that are quadratic
+/// in the size and would bench eternally at the top of a linear sweep.
+pub fn quadratic_logsizes(sweep: RangeInclusive<usize>) -> RangeInclusive<usize> {
+    let (min, max) = sweep.into_inner();
+    let cap = env_or("ZKALC_QUADRATIC_MAX", QUADRATIC_MAX_LOGSIZE);
+    if cap < min {
+        eprintln!(
+            "zkalc: ignoring ZKALC_QUADRATIC_MAX={}: the sweep starts at {}",
+            cap, min
+        );
+        return min..=max.min(QUADRATIC_MAX_LOGSIZE.max(min));
+    }
+    min..=max.min(cap)
+}
+
+/// The criterion sample size for a sweep point of size `2^logsize`.
+///
+/// `ZKALC_SAMPLES` applies to every size. By default criterion's 100 samples
+/// are taken, dropping to 10 above 2^20 so that big MSMs don't bench eternally.
+pub fn sample_size(logsize: usize) -> usize {
+    let default = if logsize > 20 { 10 } else { 100 };
+    let samples = env_or("ZKALC_SAMPLES", default);
+    // criterion refuses fewer than 10 samples
+    if samples < 10 {
+        eprintln!(
+            "zkalc: ignoring ZKALC_SAMPLES={}: criterion needs at least 10",
+            samples
+        );
+        return default;
+    }
+    samples
+}
+
+/// The operations of the shared benchmarks (see [`crate::benches`]) that
+/// `ZKALC_ONLY` can select.
+pub const OPERATIONS: &[&str] = &[
+    "add_ff",
+    "mul_ff",
+    "invert",
+    "add_G1",
+    "add_G2",
+    "scalar_mul",
+    "msm",
+    "pairing",
+];
+
+/// Whether the shared benchmarks of `operation`, one of [`OPERATIONS`], run.
+///
+/// `ZKALC_ONLY` is a comma-separated list of the operations to run, e.g.
+/// `msm,pairing`; if unset, all of them run. Unlike criterion's filter, which
+/// still generates the inputs of the benchmarks it skips, this skips the
+/// setup too. Unknown names are reported on stderr, once, and ignored.
+pub fn selected(operation: &str) -> bool {
+    static ONLY: OnceLock<Option<Vec<String>>> = OnceLock::new();
+    let only = ONLY.get_or_init(|| {
+        let value = env::var("ZKALC_ONLY").ok()?;
+        let (known, unknown): (Vec<_>, Vec<_>) = value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .partition(|name| OPERATIONS.contains(name));
+        if !unknown.is_empty() {
+            eprintln!(
+                "zkalc: ignoring unknown operations {} in ZKALC_ONLY: valid ones are {}",
+                unknown.join(","),
+                OPERATIONS.join(","),
+            );
+        }
+        if known.is_empty() {
+            eprintln!("zkalc: ignoring ZKALC_ONLY={:?}: it selects nothing", value);
+            return None;
+        }
+        Some(known.into_iter().map(String::from).collect())
+    });
+    only.as_ref()
+        .is_none_or(|only| only.iter().any(|name| name == operation))
+}
+
+/// The seed of every benchmark RNG: `ZKALC_SEED`, or a random seed if unset.
+///
+/// Either way the seed is logged once on stderr, so that the inputs of any run
+/// can be reproduced by setting `ZKALC_SEED` to it.
+pub fn seed() -> u64 {
+    static SEED: OnceLock<u64> = OnceLock::new();
+    *SEED.get_or_init(|| {
+        let seed = env_or("ZKALC_SEED", rand::thread_rng().gen());
+        eprintln!("zkalc: ZKALC_SEED={}", seed);
+        seed
+    })
+}
+
+/// The RNG that benchmark inputs are drawn from, seeded with [`seed`].
+///
+/// Every call starts the same stream, so a bench's inputs do not depend on
+/// which other benches ran before it.
+pub fn rng() -> StdRng {
+    StdRng::seed_from_u64(seed())
+}
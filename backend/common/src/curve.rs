@@ -0,0 +1,147 @@
+//! A common interface to the curves of all backends.
+//!
+//! Backends implement [`Curve`], and [`PairingCurve`] for pairing-friendly
+//! curves, then call the generic benchmarks of [`crate::benches`]. This way the
+//! same operation gets the same benchmark id on every backend.
+
+use std::ops::{Add, Mul};
+
+use rand::RngCore;
+
+use crate::config;
+
+pub trait Curve {
+    /// The name of the curve, e.g. `bls12_381`, which prefixes its benchmark ids.
+    const NAME: &'static str;
+    /// The default log2 size of the largest MSM benchmarked, see
+    /// [`config::msm_logsizes_up_to`].
+    const MSM_MAX_LOGSIZE: usize = config::MSM_MAX_LOGSIZE;
+
+    type Scalar: Copy + Add<Output = Self::Scalar> + Mul<Output = Self::Scalar>;
+    type G1: Copy + Add<Output = Self::G1> + Mul<Self::Scalar, Output = Self::G1>;
+    /// The form of the MSM bases taken by the backend's MSM, e.g. affine points.
+    type G1Base: Clone;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> Self::Scalar;
+    fn rand_g1(rng: &mut impl RngCore) -> Self::G1;
+    fn invert(x: &Self::Scalar) -> Option<Self::Scalar>;
+
+    /// Converts a point to an MSM base. This happens outside the timed loop.
+    fn g1_base(p: &Self::G1) -> Self::G1Base;
+    fn msm_g1(bases: &[Self::G1Base], scalars: &[Self::Scalar]) -> Self::G1;
+}
+
+pub trait PairingCurve: Curve {
+    type G2: Copy + Add<Output = Self::G2> + Mul<Self::Scalar, Output = Self::G2>;
+    type G2Base: Clone;
+    /// The pairing inputs, usually the affine points.
+    type G1Affine: Copy;
+    type G2Affine: Copy;
+    type Gt;
+
+    fn rand_g2(rng: &mut impl RngCore) -> Self::G2;
+    fn g2_base(p: &Self::G2) -> Self::G2Base;
+    fn msm_g2(bases: &[Self::G2Base], scalars: &[Self::Scalar]) -> Self::G2;
+
+    fn g1_to_affine(p: &Self::G1) -> Self::G1Affine;
+    fn g2_to_affine(p: &Self::G2) -> Self::G2Affine;
+    fn pairing(a: &Self::G1Affine, b: &Self::G2Affine) -> Self::Gt;
+}
+
+/// A toy curve for the tests of the generic code.
+#[cfg(test)]
+pub(crate) mod mock {
+    use rand::Rng;
+
+    use super::*;
+
+    const P: u64 = 101;
+
+    /// Integers mod a small prime, standing in for both the scalars and the
+    /// points of a curve.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub(crate) struct Zp(pub(crate) u64);
+
+    impl Add for Zp {
+        type Output = Zp;
+        fn add(self, rhs: Zp) -> Zp {
+            Zp((self.0 + rhs.0) % P)
+        }
+    }
+
+    impl Mul for Zp {
+        type Output = Zp;
+        fn mul(self, rhs: Zp) -> Zp {
+            Zp(self.0 * rhs.0 % P)
+        }
+    }
+
+    /// A "curve" whose groups are Z_p and whose pairing is multiplication,
+    /// which is bilinear.
+    pub(crate) struct Mock;
+
+    impl Curve for Mock {
+        const NAME: &'static str = "mock";
+
+        type Scalar = Zp;
+        type G1 = Zp;
+        type G1Base = Zp;
+
+        // Zero is left out so that the invert bench never fails
+        fn rand_scalar(rng: &mut impl RngCore) -> Zp {
+            Zp(rng.gen_range(1..P))
+        }
+
+        fn rand_g1(rng: &mut impl RngCore) -> Zp {
+            Zp(rng.gen_range(0..P))
+        }
+
+        fn invert(x: &Zp) -> Option<Zp> {
+            // Fermat: x^(p-2)
+            (x.0 != 0).then(|| (0..P - 2).fold(Zp(1), |acc, _| acc * *x))
+        }
+
+        fn g1_base(p: &Zp) -> Zp {
+            *p
+        }
+
+        fn msm_g1(bases: &[Zp], scalars: &[Zp]) -> Zp {
+            bases
+                .iter()
+                .zip(scalars)
+                .fold(Zp(0), |acc, (&b, &s)| acc + b * s)
+        }
+    }
+
+    impl PairingCurve for Mock {
+        type G2 = Zp;
+        type G2Base = Zp;
+        type G1Affine = Zp;
+        type G2Affine = Zp;
+        type Gt = Zp;
+
+        fn rand_g2(rng: &mut impl RngCore) -> Zp {
+            Self::rand_g1(rng)
+        }
+
+        fn g2_base(p: &Zp) -> Zp {
+            *p
+        }
+
+        fn msm_g2(bases: &[Zp], scalars: &[Zp]) -> Zp {
+            Self::msm_g1(bases, scalars)
+        }
+
+        fn g1_to_affine(p: &Zp) -> Zp {
+            *p
+        }
+
+        fn g2_to_affine(p: &Zp) -> Zp {
+            *p
+        }
+
+        fn pairing(a: &Zp, b: &Zp) -> Zp {
+            *a * *b
+        }
+    }
+}
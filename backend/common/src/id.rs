@@ -0,0 +1,146 @@
+//! Parsing of criterion benchmark ids.
+//!
+//! All backends name their benchmarks `<curve>/<operation...>[/<size>]`: the
+//! first component is the curve, a trailing numeric component is the input
+//! size and the components in between, joined with `_`, name the operation.
+//! So `bls12_381/msm/G1/1024` is curve `bls12_381`, operation `msm_G1` and
+//! size `1024`, and `bls12_381/pairing` has no size.
+
+use std::fmt;
+
+/// The curve, operation and optional size that a benchmark id names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BenchKey {
+    pub curve: String,
+    pub operation: String,
+    pub size: Option<u64>,
+}
+
+/// A benchmark id that does not follow the `curve/operation[/size]`
+/// convention. Each variant carries the id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// No operation after the curve, as in `pairing` or `bls12_381/1024`.
+    MissingOperation(String),
+    /// An empty component, as in `bls12_381//G1` or `bls12_381/msm/`.
+    EmptyComponent(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingOperation(id) => {
+                write!(f, "benchmark id '{}' names no curve and operation", id)
+            }
+            ParseError::EmptyComponent(id) => {
+                write!(f, "benchmark id '{}' has an empty component", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Splits a benchmark id into its curve, operation and optional size.
+pub fn parse_benchmark_id(id: &str) -> Result<BenchKey, ParseError> {
+    let mut parts: Vec<&str> = id.split('/').collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(ParseError::EmptyComponent(id.to_string()));
+    }
+    let size = match parts.last().map(|last| last.parse::<u64>()) {
+        Some(Ok(size)) => {
+            parts.pop();
+            Some(size)
+        }
+        _ => None,
+    };
+    if parts.len() < 2 {
+        return Err(ParseError::MissingOperation(id.to_string()));
+    }
+    Ok(BenchKey {
+        curve: parts[0].to_string(),
+        operation: parts[1..].join("_"),
+        size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(curve: &str, operation: &str, size: Option<u64>) -> BenchKey {
+        BenchKey {
+            curve: curve.to_string(),
+            operation: operation.to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn parses_sized_ids() {
+        for (id, expected) in [
+            // `bench_with_input` of a group, with a function id
+            (
+                "bls12_381/msm/G1/1024",
+                key("bls12_381", "msm_G1", Some(1024)),
+            ),
+            // `BenchmarkId::from_parameter`, only the size
+            (
+                "curve25519/msm/65536",
+                key("curve25519", "msm", Some(65536)),
+            ),
+            (
+                "bls12_381/fft/inverse/1",
+                key("bls12_381", "fft_inverse", Some(1)),
+            ),
+            // numbers inside the operation are not sizes
+            (
+                "bls12_381/msm_chunked/G1/chunk_65536/1024",
+                key("bls12_381", "msm_chunked_G1_chunk_65536", Some(1024)),
+            ),
+            (
+                "bls12_381/msm_bits/G1/64_bits/1024",
+                key("bls12_381", "msm_bits_G1_64_bits", Some(1024)),
+            ),
+        ] {
+            assert_eq!(parse_benchmark_id(id), Ok(expected), "{}", id);
+        }
+    }
+
+    #[test]
+    fn parses_unsized_ids() {
+        for (id, expected) in [
+            // `bench_function` on the Criterion itself
+            ("bls12_381/pairing", key("bls12_381", "pairing", None)),
+            // `bench_function` of a group named after the curve
+            ("pallas/add_ff", key("pallas", "add_ff", None)),
+            (
+                "bls12_381/scalar_mul/G1/glv",
+                key("bls12_381", "scalar_mul_G1_glv", None),
+            ),
+            ("bls12_381/bls/verify", key("bls12_381", "bls_verify", None)),
+        ] {
+            assert_eq!(parse_benchmark_id(id), Ok(expected), "{}", id);
+        }
+    }
+
+    #[test]
+    fn rejects_ids_without_an_operation() {
+        for id in ["pairing", "1024", "bls12_381/1024"] {
+            assert_eq!(
+                parse_benchmark_id(id),
+                Err(ParseError::MissingOperation(id.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_empty_components() {
+        for id in ["", "bls12_381//G1", "bls12_381/msm/", "/msm/1024"] {
+            assert_eq!(
+                parse_benchmark_id(id),
+                Err(ParseError::EmptyComponent(id.to_string()))
+            );
+        }
+    }
+}
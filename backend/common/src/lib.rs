@@ -0,0 +1,14 @@
+//! Code shared by the zkalc backends and the tooling that post-processes their
+//! benchmark results.
+
+pub mod baseline;
+#[cfg(feature = "criterion")]
+pub mod benches;
+pub mod config;
+pub mod curve;
+pub mod id;
+pub mod machine;
+pub mod memory;
+pub mod msm;
+pub mod report;
+pub mod results;
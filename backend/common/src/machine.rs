@@ -0,0 +1,81 @@
+//! A description of the machine that results were measured on.
+//!
+//! Everything is best effort: what cannot be found out on a platform reads
+//! `unknown`, or is left out.
+
+use std::fs;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+const UNKNOWN: &str = "unknown";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MachineInfo {
+    /// The CPU's brand string, e.g. `AMD Ryzen 9 5950X 16-Core Processor`.
+    pub cpu: String,
+    /// The number of threads that can run in parallel, as
+    /// [`std::thread::available_parallelism`] counts them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cores: Option<usize>,
+    /// The target triple the tooling was built for.
+    pub target: String,
+    /// Whether the tooling was built with optimizations. Criterion benchmarks
+    /// always are, but the memory measurements run in the tooling itself.
+    pub release: bool,
+    /// The global allocator the benchmarks were built with, e.g. `jemalloc`,
+    /// if it is known. It cannot be detected after the fact, so it is given
+    /// when the results are collected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocator: Option<String>,
+}
+
+impl MachineInfo {
+    /// Describes the machine this runs on.
+    pub fn detect() -> Self {
+        MachineInfo {
+            cpu: cpu_brand().unwrap_or_else(|| UNKNOWN.to_string()),
+            cores: std::thread::available_parallelism().map(|n| n.get()).ok(),
+            target: env!("ZKALC_TARGET").to_string(),
+            release: !cfg!(debug_assertions),
+            allocator: None,
+        }
+    }
+}
+
+/// The CPU's brand string, from `/proc/cpuinfo` on Linux or `sysctl` on macOS.
+fn cpu_brand() -> Option<String> {
+    let from_cpuinfo = || {
+        let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
+        cpuinfo.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "model name").then(|| value.trim().to_string())
+        })
+    };
+    let from_sysctl = || {
+        let output = Command::new("sysctl")
+            .args(["-n", "machdep.cpu.brand_string"])
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    from_cpuinfo()
+        .or_else(from_sysctl)
+        .filter(|brand| !brand.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_this_machine() {
+        let machine = MachineInfo::detect();
+        assert!(!machine.cpu.is_empty());
+        assert!(machine.target.contains(std::env::consts::ARCH));
+        assert_ne!(machine.cores, Some(0));
+    }
+}
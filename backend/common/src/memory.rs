@@ -0,0 +1,145 @@
+//! Heap usage of MSMs, measured with a counting global allocator.
+//!
+//! A backend installs [`CountingAllocator`] as the global allocator of a
+//! dedicated binary, so that the benchmarks keep the system allocator:
+//!
+//! ```ignore
+//! use zkalc_common::memory::{msm_memory, CountingAllocator};
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! fn main() {
+//!     msm_memory::<Bls12_381>().to_writer(std::io::stdout()).unwrap();
+//! }
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use crate::config;
+use crate::curve::Curve;
+use crate::machine::MachineInfo;
+use crate::msm::msm;
+use crate::results::{BenchResult, Results, Seconds};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, keeping count of the bytes currently allocated and of
+/// their peak.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn add(size: usize) {
+        let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn sub(size: usize) {
+        ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Runs `f`, returning its output and the peak number of bytes allocated
+    /// on top of what was already allocated before the call.
+    pub fn peak_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let start = ALLOCATED.load(Ordering::Relaxed);
+        PEAK.store(start, Ordering::Relaxed);
+        let output = f();
+        (output, PEAK.load(Ordering::Relaxed).saturating_sub(start))
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::sub(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::add(new_size);
+            Self::sub(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Runs one G1 MSM of every size of the curve's MSM sweep (see
+/// [`config::msm_logsizes_up_to`]) and records its wall-clock time and peak
+/// heap usage, as operation `msm_G1`.
+///
+/// The peak is only measured if [`CountingAllocator`] is the global allocator;
+/// otherwise it reads zero. As that counts the system allocator's
+/// allocations, the results name it as their allocator.
+pub fn msm_memory<C: Curve>() -> Results {
+    let mut rng = config::rng();
+    // A first MSM allocates any lazily initialized state, e.g. a thread pool,
+    // so that it is not attributed to the smallest size
+    let warm_up = [
+        C::g1_base(&C::rand_g1(&mut rng)),
+        C::g1_base(&C::rand_g1(&mut rng)),
+    ];
+    msm::<C>(
+        &warm_up,
+        &[C::rand_scalar(&mut rng), C::rand_scalar(&mut rng)],
+    )
+    .unwrap();
+
+    let mut results = Vec::new();
+    for logsize in config::msm_logsizes_up_to(C::MSM_MAX_LOGSIZE) {
+        let size = 1 << logsize;
+        let scalars: Vec<_> = (0..size).map(|_| C::rand_scalar(&mut rng)).collect();
+        let bases: Vec<_> = (0..size)
+            .map(|_| C::g1_base(&C::rand_g1(&mut rng)))
+            .collect();
+
+        let start = Instant::now();
+        let (_, peak) = CountingAllocator::peak_during(|| msm::<C>(&bases, &scalars).unwrap());
+        let seconds = start.elapsed().as_secs_f64();
+
+        let seconds = Seconds {
+            mean: seconds,
+            median: seconds,
+            std_dev: 0.0,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        results.push(BenchResult {
+            peak_bytes: Some(peak as u64),
+            ..BenchResult::new(
+                C::NAME.to_string(),
+                "msm_G1".to_string(),
+                Some(size as u64),
+                seconds,
+                1,
+            )
+        });
+    }
+    Results {
+        machine: Some(MachineInfo {
+            allocator: Some("system".to_string()),
+            ..MachineInfo::detect()
+        }),
+        ..Results::new(results)
+    }
+}
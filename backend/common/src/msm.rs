@@ -0,0 +1,100 @@
+//! MSM entry points that validate their inputs before calling the backend.
+//!
+//! Backends' MSMs disagree on mismatched or empty inputs: some panic, some
+//! silently truncate to the shorter slice and some return a wrong result. These
+//! wrappers reject both cases with an [`MsmError`] instead. A single term is a
+//! valid MSM, which backends whose MSM gets it wrong handle in their
+//! [`Curve::msm_g1`].
+
+use std::fmt;
+
+use crate::curve::{Curve, PairingCurve};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MsmError {
+    /// The number of points differs from the number of scalars.
+    LengthMismatch { points: usize, scalars: usize },
+    /// There are no terms at all.
+    Empty,
+}
+
+impl fmt::Display for MsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MsmError::LengthMismatch { points, scalars } => {
+                write!(f, "MSM over {} points but {} scalars", points, scalars)
+            }
+            MsmError::Empty => write!(f, "MSM over no terms"),
+        }
+    }
+}
+
+impl std::error::Error for MsmError {}
+
+fn check_lengths(points: usize, scalars: usize) -> Result<(), MsmError> {
+    if points != scalars {
+        return Err(MsmError::LengthMismatch { points, scalars });
+    }
+    if points == 0 {
+        return Err(MsmError::Empty);
+    }
+    Ok(())
+}
+
+/// `sum(scalars[i] * points[i])` in G1.
+pub fn msm<C: Curve>(points: &[C::G1Base], scalars: &[C::Scalar]) -> Result<C::G1, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    Ok(C::msm_g1(points, scalars))
+}
+
+/// `sum(scalars[i] * points[i])` in G2.
+pub fn msm_g2<C: PairingCurve>(
+    points: &[C::G2Base],
+    scalars: &[C::Scalar],
+) -> Result<C::G2, MsmError> {
+    check_lengths(points.len(), scalars.len())?;
+    Ok(C::msm_g2(points, scalars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::mock::{Mock, Zp};
+
+    #[test]
+    fn msm_matches_backend() {
+        let points = [Zp(3), Zp(5)];
+        let scalars = [Zp(2), Zp(7)];
+        assert_eq!(msm::<Mock>(&points, &scalars), Ok(Zp(41)));
+        assert_eq!(msm_g2::<Mock>(&points, &scalars), Ok(Zp(41)));
+    }
+
+    #[test]
+    fn msm_accepts_a_single_term() {
+        assert_eq!(msm::<Mock>(&[Zp(3)], &[Zp(7)]), Ok(Zp(21)));
+        assert_eq!(msm_g2::<Mock>(&[Zp(3)], &[Zp(7)]), Ok(Zp(21)));
+    }
+
+    #[test]
+    fn msm_rejects_mismatched_lengths() {
+        let err = MsmError::LengthMismatch {
+            points: 2,
+            scalars: 1,
+        };
+        assert_eq!(msm::<Mock>(&[Zp(3), Zp(5)], &[Zp(2)]), Err(err.clone()));
+        assert_eq!(msm_g2::<Mock>(&[Zp(3), Zp(5)], &[Zp(2)]), Err(err));
+        assert_eq!(
+            msm::<Mock>(&[], &[Zp(2)]),
+            Err(MsmError::LengthMismatch {
+                points: 0,
+                scalars: 1
+            })
+        );
+    }
+
+    #[test]
+    fn msm_rejects_empty_inputs() {
+        assert_eq!(msm::<Mock>(&[], &[]), Err(MsmError::Empty));
+        assert_eq!(msm_g2::<Mock>(&[], &[]), Err(MsmError::Empty));
+    }
+}
@@ -0,0 +1,351 @@
+//! Side-by-side comparison of two backends' results.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::results::Results;
+
+/// How much faster one side must be for the row to be flagged.
+pub const FLAG_RATIO: f64 = 1.5;
+
+/// One operation benchmarked by both backends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    /// The machine of both sides, in results merged from several machines.
+    pub machine: Option<String>,
+    pub curve: String,
+    pub operation: String,
+    pub size: Option<u64>,
+    /// Median seconds of the left-hand backend.
+    pub left: f64,
+    /// Median seconds of the right-hand backend.
+    pub right: f64,
+}
+
+impl Row {
+    /// How many times slower the left-hand backend is, or `None` if the
+    /// right-hand median is zero.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.right > 0.0).then(|| self.left / self.right)
+    }
+
+    fn flag(&self) -> &'static str {
+        let Some(ratio) = self.ratio() else {
+            return "";
+        };
+        if ratio > FLAG_RATIO {
+            ">"
+        } else if ratio < 1.0 / FLAG_RATIO {
+            "<"
+        } else {
+            ""
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub left_name: String,
+    pub right_name: String,
+    pub rows: Vec<Row>,
+    /// Benchmarks that only one of the backends has, and were skipped.
+    pub skipped: usize,
+}
+
+impl Report {
+    /// Aligns the benchmarks of `left` and `right` by machine, curve, operation
+    /// and size, so that in results merged from several machines (see
+    /// [`Results::merge`]) each machine is only compared with itself.
+    ///
+    /// With `curves = Some((a, b))` only curve `a` of `left` and curve `b` of
+    /// `right` are compared, aligned by operation and size. This answers
+    /// questions such as "how does BN254 on one backend compare to BLS12-381 on
+    /// another".
+    pub fn new(
+        left_name: &str,
+        left: &Results,
+        right_name: &str,
+        right: &Results,
+        curves: Option<(&str, &str)>,
+    ) -> Self {
+        let left_index = medians(left, curves.map(|(left, _)| left));
+        let right_index = medians(right, curves.map(|(_, right)| right));
+
+        let mut rows = Vec::new();
+        for (&(machine, curve, operation, size), &left) in &left_index {
+            if let Some(&right) = right_index.get(&(machine, curve, operation, size)) {
+                let curve = match curves {
+                    Some((left, right)) if left == right => left.to_string(),
+                    Some((left, right)) => format!("{} vs {}", left, right),
+                    None => curve.to_string(),
+                };
+                rows.push(Row {
+                    machine: machine.map(str::to_string),
+                    curve,
+                    operation: operation.to_string(),
+                    size,
+                    left,
+                    right,
+                });
+            }
+        }
+        let skipped = left_index.len() + right_index.len() - 2 * rows.len();
+
+        Report {
+            left_name: left_name.to_string(),
+            right_name: right_name.to_string(),
+            rows,
+            skipped,
+        }
+    }
+
+    fn cells(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        // Only merged results name a machine per row
+        let machines = self.rows.iter().any(|row| row.machine.is_some());
+        let mut header = vec![
+            "curve".to_string(),
+            "operation".to_string(),
+            "size".to_string(),
+            self.left_name.clone(),
+            self.right_name.clone(),
+            "ratio".to_string(),
+            String::new(),
+        ];
+        if machines {
+            header.insert(0, "machine".to_string());
+        }
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut cells = vec![
+                    row.curve.clone(),
+                    row.operation.clone(),
+                    row.size.map(|s| s.to_string()).unwrap_or_default(),
+                    format_seconds(row.left),
+                    format_seconds(row.right),
+                    row.ratio()
+                        .map(|ratio| format!("{:.2}x", ratio))
+                        .unwrap_or_else(|| "n/a".to_string()),
+                    row.flag().to_string(),
+                ];
+                if machines {
+                    cells.insert(0, row.machine.clone().unwrap_or_default());
+                }
+                cells
+            })
+            .collect();
+        (header, rows)
+    }
+
+    fn legend(&self) -> String {
+        format!(
+            "ratio = {} / {}; '>' marks rows where {} is more than {}x faster, '<' where {} is.\n\
+             {} benchmarks present in only one backend were skipped.\n",
+            self.left_name,
+            self.right_name,
+            self.right_name,
+            FLAG_RATIO,
+            self.left_name,
+            self.skipped,
+        )
+    }
+
+    /// Renders the report as a plain-text table.
+    pub fn to_text(&self) -> String {
+        let (header, rows) = self.cells();
+        let widths: Vec<usize> = (0..header.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].len())
+                    .chain([header[i].len()])
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        let mut out = String::new();
+        for row in [&header].into_iter().chain(&rows) {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            writeln!(out, "{}", line.join("  ").trim_end()).unwrap();
+        }
+        writeln!(out).unwrap();
+        out.push_str(&self.legend());
+        out
+    }
+
+    /// Renders the report as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let (header, rows) = self.cells();
+        let mut out = String::new();
+        writeln!(out, "| {} |", header.join(" | ")).unwrap();
+        writeln!(out, "|{}", "---|".repeat(header.len())).unwrap();
+        for row in rows {
+            writeln!(
+                out,
+                "| {} |",
+                row.join(" | ").replace('>', "&gt;").replace('<', "&lt;")
+            )
+            .unwrap();
+        }
+        writeln!(out).unwrap();
+        out.push_str(&self.legend());
+        out
+    }
+}
+
+type Key<'a> = (Option<&'a str>, &'a str, &'a str, Option<u64>);
+
+/// The median seconds of each benchmark of `results` on `curve`, keyed by
+/// machine, curve, operation and size. The curve is left empty when filtering
+/// on one.
+fn medians<'a>(results: &'a Results, curve: Option<&str>) -> BTreeMap<Key<'a>, f64> {
+    results
+        .results
+        .iter()
+        .filter(|r| curve.is_none_or(|curve| r.curve == curve))
+        .map(|r| {
+            let row_curve = if curve.is_some() {
+                ""
+            } else {
+                r.curve.as_str()
+            };
+            let key = (
+                r.machine.as_deref(),
+                row_curve,
+                r.operation.as_str(),
+                r.size,
+            );
+            (key, r.seconds.median)
+        })
+        .collect()
+}
+
+/// Formats a duration with a unit that keeps the number readable.
+pub fn format_seconds(seconds: f64) -> String {
+    if seconds >= 1.0 {
+        format!("{:.2} s", seconds)
+    } else if seconds >= 1e-3 {
+        format!("{:.2} ms", seconds * 1e3)
+    } else if seconds >= 1e-6 {
+        format!("{:.2} µs", seconds * 1e6)
+    } else {
+        format!("{:.2} ns", seconds * 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::results::{BenchResult, Seconds};
+
+    fn result(curve: &str, operation: &str, size: Option<u64>, median: f64) -> BenchResult {
+        let seconds = Seconds {
+            mean: median,
+            median,
+            std_dev: 0.0,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        BenchResult::new(curve.into(), operation.into(), size, seconds, 100)
+    }
+
+    fn row(curve: &str, operation: &str, size: Option<u64>, left: f64, right: f64) -> Row {
+        Row {
+            machine: None,
+            curve: curve.into(),
+            operation: operation.into(),
+            size,
+            left,
+            right,
+        }
+    }
+
+    #[test]
+    fn matches_benchmarks_of_both_sides() {
+        let left = Results::new(vec![
+            result("bls12_381", "pairing", None, 1e-3),
+            result("bls12_381", "msm_G1", Some(1024), 4e-3),
+            result("bls12_381", "msm_G1", Some(2048), 8e-3),
+            result("bn254", "pairing", None, 6e-4),
+        ]);
+        let right = Results::new(vec![
+            result("bls12_381", "msm_G1", Some(1024), 2e-3),
+            result("bls12_381", "pairing", None, 1e-3),
+            result("bls12_381", "hash_to_G1", None, 1e-4),
+        ]);
+
+        let report = Report::new("blstrs", &left, "arkworks", &right, None);
+        assert_eq!(
+            report.rows,
+            vec![
+                row("bls12_381", "msm_G1", Some(1024), 4e-3, 2e-3),
+                row("bls12_381", "pairing", None, 1e-3, 1e-3),
+            ]
+        );
+        // msm_G1/2048 and the BN254 pairing on the left, hash_to_G1 on the right
+        assert_eq!(report.skipped, 3);
+        assert_eq!(report.rows[0].ratio(), Some(2.0));
+        assert_eq!(report.rows[0].flag(), ">");
+        assert_eq!(report.rows[1].flag(), "");
+    }
+
+    #[test]
+    fn compares_different_curves() {
+        let left = Results::new(vec![
+            result("bn254", "pairing", None, 6e-4),
+            result("bls12_381", "pairing", None, 1e-3),
+        ]);
+        let right = Results::new(vec![result("bls12_381", "pairing", None, 1e-3)]);
+
+        let report = Report::new("a", &left, "b", &right, Some(("bn254", "bls12_381")));
+        assert_eq!(
+            report.rows,
+            vec![row("bn254 vs bls12_381", "pairing", None, 6e-4, 1e-3)]
+        );
+        assert_eq!(report.skipped, 0);
+        assert_eq!(report.rows[0].flag(), "<");
+    }
+
+    #[test]
+    fn zero_right_hand_medians_have_no_ratio() {
+        let zero = row("bls12_381", "noop", None, 1e-9, 0.0);
+        assert_eq!(zero.ratio(), None);
+        assert_eq!(zero.flag(), "");
+
+        let report = Report {
+            left_name: "a".into(),
+            right_name: "b".into(),
+            rows: vec![zero],
+            skipped: 0,
+        };
+        assert!(report.to_text().contains("n/a"));
+    }
+
+    #[test]
+    fn compares_each_machine_with_itself() {
+        let on = |machine: &str, median| BenchResult {
+            machine: Some(machine.into()),
+            ..result("bls12_381", "pairing", None, median)
+        };
+        let left = Results::new(vec![on("laptop", 2e-3), on("server", 1e-3)]);
+        let right = Results::new(vec![on("laptop", 1e-3), on("server", 1e-3)]);
+
+        let report = Report::new("a", &left, "b", &right, None);
+        let laptop = Row {
+            machine: Some("laptop".into()),
+            ..row("bls12_381", "pairing", None, 2e-3, 1e-3)
+        };
+        let server = Row {
+            machine: Some("server".into()),
+            ..row("bls12_381", "pairing", None, 1e-3, 1e-3)
+        };
+        assert_eq!(report.rows, vec![laptop, server]);
+        assert!(report.to_text().starts_with("machine"));
+    }
+}
@@ -0,0 +1,1076 @@
+//! Structured benchmark results.
+//!
+//! All backends' criterion output is collected into a single JSON schema that
+//! the frontend ingests directly:
+//!
+//! ```json
+//! {
+//!   "schema_version": 2,
+//!   "results": [
+//!     {
+//!       "curve": "bls12_381",
+//!       "operation": "msm_G1",
+//!       "size": 1024,
+//!       "seconds": { "mean": 0.00121, "median": 0.00120, "std_dev": 0.00002 },
+//!       "samples": 100,
+//!       "elements_per_second": 846281.0
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `curve`, `operation` and `size` are derived from the criterion benchmark id
+//! (see [`crate::id`]), so `bls12_381/msm/G1/1024` becomes curve `bls12_381`,
+//! operation `msm_G1` and size `1024`. `size` is omitted for operations that
+//! take no size parameter, such as `bls12_381/pairing`.
+//!
+//! All times are per iteration and in seconds. `samples` is the number of
+//! samples criterion took. The mean is also given as a throughput: operations
+//! without a size get `ops_per_second`, sized ones such as MSMs get
+//! `elements_per_second`, the size over the mean time. Memory measurements (see [`crate::memory`]) also
+//! carry `peak_bytes`, the peak heap usage of a single run.
+//!
+//! Results collected from criterion also describe the spread of the samples,
+//! for error bars: `seconds` carries the `median_abs_dev`, `min` and `max`
+//! per-iteration times, and `outliers` is the number of samples that criterion
+//! classifies as outliers, those more than 1.5 interquartile ranges outside
+//! the quartiles.
+//!
+//! The cheapest operations are calibrated against criterion's own overhead:
+//! the shared benchmarks time an empty closure as operation `noop` of every
+//! curve, and when criterion output is collected, that time is subtracted
+//! from each operation of the curve without a size whose mean is below
+//! [`CALIBRATION_RATIO`] times the noop's. The mean, median, `min` and `max`
+//! are shifted and the throughput recomputed, while the measured times are
+//! kept as `raw_seconds` (see [`Results::subtract_overhead`]). Operations whose
+//! mean or median is not above the noop's are kept as measured.
+//!
+//! Files written when the results are collected also describe the `machine`
+//! they were measured on, in a top-level block next to `schema_version`: its
+//! `cpu`, number of `cores`, `target` triple and whether the tooling was a
+//! `release` build (see [`MachineInfo`]).
+//!
+//! Results of several machines merged into one file (see [`Results::merge`])
+//! instead name the `machine` that each was measured on, a label given when
+//! merging, and the file has no machine block.
+//!
+//! Files are written with the current [`SCHEMA_VERSION`]. Files of older
+//! versions are upgraded when read (see [`migrate`]), and files of newer
+//! versions are rejected.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::id::{parse_benchmark_id, BenchKey, ParseError};
+use crate::machine::MachineInfo;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// A benchmark id that does not follow the `curve/operation[/size]` convention.
+    Id(ParseError),
+    /// A benchmark whose criterion output has no estimates.
+    BenchmarkId(String),
+    /// A benchmark measured outside criterion without any samples.
+    NoSamples(String),
+    /// A criterion time unit we do not know how to convert.
+    Unit(String),
+    /// Two results files to merge under the same machine label.
+    DuplicateMachine(String),
+    /// A results file written by a newer version of the tooling.
+    SchemaVersion(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Json(e) => write!(f, "invalid JSON: {}", e),
+            Error::Id(e) => write!(f, "{}", e),
+            Error::BenchmarkId(id) => write!(f, "no measurements for benchmark '{}'", id),
+            Error::NoSamples(id) => write!(f, "no samples for benchmark '{}'", id),
+            Error::Unit(unit) => write!(f, "unknown time unit '{}'", unit),
+            Error::DuplicateMachine(label) => {
+                write!(f, "more than one results file for machine '{}'", label)
+            }
+            Error::SchemaVersion(version) => write!(
+                f,
+                "results schema version {} is newer than the supported version {}",
+                version, SCHEMA_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Id(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Seconds {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub median_abs_dev: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+/// The measurements of a single benchmark.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub machine: Option<String>,
+    pub curve: String,
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    pub seconds: Seconds,
+    pub samples: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ops_per_second: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elements_per_second: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_seconds: Option<Seconds>,
+}
+
+impl BenchResult {
+    /// A result with its throughput derived from the mean time.
+    pub fn new(
+        curve: String,
+        operation: String,
+        size: Option<u64>,
+        seconds: Seconds,
+        samples: usize,
+    ) -> Self {
+        let per_second = (seconds.mean > 0.0).then(|| 1.0 / seconds.mean);
+        let (ops_per_second, elements_per_second) = match size {
+            Some(size) => (None, per_second.map(|rate| size as f64 * rate)),
+            None => (per_second, None),
+        };
+        BenchResult {
+            machine: None,
+            curve,
+            operation,
+            size,
+            seconds,
+            samples,
+            ops_per_second,
+            elements_per_second,
+            peak_bytes: None,
+            outliers: None,
+            raw_seconds: None,
+        }
+    }
+
+    /// Identifies the benchmark across runs and backends.
+    pub fn key(&self) -> (&str, &str, Option<u64>) {
+        (&self.curve, &self.operation, self.size)
+    }
+
+    /// Summarizes per-iteration times in seconds, measured outside criterion,
+    /// under the same naming convention as a criterion benchmark id.
+    pub fn from_samples(id: &str, per_iteration: &[f64]) -> Result<Self, Error> {
+        let BenchKey {
+            curve,
+            operation,
+            size,
+        } = parse_benchmark_id(id)?;
+        if per_iteration.is_empty() {
+            return Err(Error::NoSamples(id.to_string()));
+        }
+        let sorted = ascending(per_iteration);
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let seconds = Seconds {
+            mean: sorted.iter().sum::<f64>() / n as f64,
+            median,
+            std_dev: std_dev(&sorted),
+            median_abs_dev: Some(median_abs_dev(&sorted, median)),
+            min: Some(sorted[0]),
+            max: Some(sorted[n - 1]),
+        };
+        Ok(BenchResult {
+            outliers: Some(outliers(&sorted)),
+            ..BenchResult::new(curve, operation, size, seconds, n)
+        })
+    }
+}
+
+/// Operations that take less than this many times the `noop` benchmark of
+/// their curve, i.e. whose overhead is more than 1% of their time, are
+/// calibrated by [`Results::subtract_overhead`].
+pub const CALIBRATION_RATIO: f64 = 100.0;
+
+/// The version of the results schema that is written. Version 1 is the
+/// schema before versioning, without the `schema_version` field.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// A results file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Results {
+    pub schema_version: u32,
+    /// The machine that the results were measured on, if it was recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub machine: Option<MachineInfo>,
+    pub results: Vec<BenchResult>,
+}
+
+impl Default for Results {
+    fn default() -> Self {
+        Results::new(Vec::new())
+    }
+}
+
+impl Results {
+    /// A results file of the current schema version.
+    pub fn new(results: Vec<BenchResult>) -> Self {
+        Results {
+            schema_version: SCHEMA_VERSION,
+            machine: None,
+            results,
+        }
+    }
+
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a results file of any supported version, upgrading it to the
+    /// current one.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, Error> {
+        migrate(serde_json::from_reader(reader)?)
+    }
+
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), Error> {
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Combines the results of several machines into one file, labelling
+    /// every result with the machine of its run, so that a result is keyed by
+    /// machine, curve, operation and size.
+    ///
+    /// The machine blocks of the runs are dropped, as the merged results
+    /// belong to several machines.
+    ///
+    /// Fails if two runs share a label, rather than mixing up their results.
+    pub fn merge<I>(runs: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (String, Results)>,
+    {
+        let mut machines = HashSet::new();
+        let mut results = Vec::new();
+        for (machine, run) in runs {
+            if !machines.insert(machine.clone()) {
+                return Err(Error::DuplicateMachine(machine));
+            }
+            results.extend(run.results.into_iter().map(|r| BenchResult {
+                machine: Some(machine.clone()),
+                ..r
+            }));
+        }
+        Ok(Results::new(results))
+    }
+
+    /// Writes the results as CSV, with columns
+    /// `curve,operation,size,mean_seconds,median_seconds,stddev_seconds`.
+    /// The size is left empty for operations without one.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writeln!(
+            writer,
+            "curve,operation,size,mean_seconds,median_seconds,stddev_seconds"
+        )?;
+        for r in &self.results {
+            writeln!(
+                writer,
+                "{},{},{},{:e},{:e},{:e}",
+                csv_field(&r.curve),
+                csv_field(&r.operation),
+                r.size.map(|s| s.to_string()).unwrap_or_default(),
+                r.seconds.mean,
+                r.seconds.median,
+                r.seconds.std_dev,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Collects the messages that `cargo criterion --message-format=json`
+    /// prints, one JSON object per line. Messages other than completed
+    /// benchmarks are skipped.
+    pub fn from_criterion_messages<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut results = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let message: CriterionMessage = serde_json::from_str(&line)?;
+            if message.reason == "benchmark-complete" {
+                results.push(message.into_result()?);
+            }
+        }
+        let mut results = Results::new(results);
+        results.subtract_overhead();
+        Ok(results)
+    }
+
+    /// Collects the benchmarks that criterion saved under `dir`, usually
+    /// `target/criterion`, from the `estimates.json` and `sample.json` of each
+    /// benchmark's `new` directory. Baselines saved next to `new` are skipped.
+    pub fn from_criterion_dir<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        let mut dirs = Vec::new();
+        find_criterion_benchmarks(dir.as_ref(), &mut dirs)?;
+        dirs.sort();
+        let results = dirs
+            .iter()
+            .map(|dir| criterion_dir_result(dir))
+            .collect::<Result<_, _>>()?;
+        let mut results = Results::new(results);
+        results.subtract_overhead();
+        Ok(results)
+    }
+
+    /// Subtracts the per-iteration overhead of criterion, the mean time of the
+    /// curve's `noop` operation, from the operations of the curve that have no
+    /// size and take less than [`CALIBRATION_RATIO`] times as long. Their
+    /// measured times are kept in `raw_seconds`; results that already have them
+    /// are left alone, so that calibrating twice changes nothing.
+    ///
+    /// Operations that do not take longer than the overhead cannot be told
+    /// apart from it and are kept as measured, rather than calibrated to a
+    /// median of zero.
+    pub fn subtract_overhead(&mut self) {
+        let noops: Vec<(String, Option<String>, f64)> = self
+            .results
+            .iter()
+            .filter(|r| r.operation == "noop" && r.size.is_none())
+            .map(|r| (r.curve.clone(), r.machine.clone(), r.seconds.mean))
+            .collect();
+        for (curve, machine, overhead) in noops {
+            for r in &mut self.results {
+                let calibrate = r.curve == curve
+                    && r.machine == machine
+                    && r.operation != "noop"
+                    && r.size.is_none()
+                    && r.raw_seconds.is_none()
+                    && r.seconds.mean < CALIBRATION_RATIO * overhead
+                    && r.seconds.mean.min(r.seconds.median) > overhead;
+                if !calibrate {
+                    continue;
+                }
+                let raw = r.seconds.clone();
+                // Single samples may still be faster than the mean overhead
+                let shift = |t: f64| (t - overhead).max(0.0);
+                let seconds = Seconds {
+                    mean: shift(raw.mean),
+                    median: shift(raw.median),
+                    min: raw.min.map(shift),
+                    max: raw.max.map(shift),
+                    ..raw.clone()
+                };
+                // Unsized, so only the operations per second change
+                let calibrated = BenchResult::new(
+                    r.curve.clone(),
+                    r.operation.clone(),
+                    r.size,
+                    seconds,
+                    r.samples,
+                );
+                r.seconds = calibrated.seconds;
+                r.ops_per_second = calibrated.ops_per_second;
+                r.raw_seconds = Some(raw);
+            }
+        }
+    }
+}
+
+/// Upgrades a parsed results file of any supported schema version to the
+/// current one, filling in the fields that older versions lack.
+pub fn migrate(file: serde_json::Value) -> Result<Results, Error> {
+    let version = match file.get("schema_version") {
+        Some(version) => serde_json::from_value(version.clone())?,
+        None => 1,
+    };
+    match version {
+        1 => Ok(serde_json::from_value::<v1::Results>(file)?.into()),
+        SCHEMA_VERSION => Ok(serde_json::from_value(file)?),
+        _ => Err(Error::SchemaVersion(version)),
+    }
+}
+
+/// The schema before versioning.
+mod v1 {
+    use serde::Deserialize;
+
+    use super::Seconds;
+
+    #[derive(Deserialize)]
+    pub(super) struct Results {
+        pub(super) results: Vec<BenchResult>,
+    }
+
+    #[derive(Deserialize)]
+    pub(super) struct BenchResult {
+        pub(super) curve: String,
+        pub(super) operation: String,
+        #[serde(default)]
+        pub(super) size: Option<u64>,
+        pub(super) seconds: Seconds,
+        pub(super) samples: usize,
+        // Unversioned files written after these fields were introduced may
+        // already carry them
+        #[serde(default)]
+        pub(super) machine: Option<String>,
+        #[serde(default)]
+        pub(super) peak_bytes: Option<u64>,
+    }
+}
+
+impl From<v1::Results> for Results {
+    fn from(v1: v1::Results) -> Self {
+        let results = v1
+            .results
+            .into_iter()
+            .map(|r| BenchResult {
+                machine: r.machine,
+                peak_bytes: r.peak_bytes,
+                ..BenchResult::new(r.curve, r.operation, r.size, r.seconds, r.samples)
+            })
+            .collect();
+        Results::new(results)
+    }
+}
+
+/// Quotes a CSV field if it contains a separator or a quote.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Pushes every `new` directory below `dir` that holds a criterion benchmark.
+fn find_criterion_benchmarks(dir: &Path, found: &mut Vec<PathBuf>) -> Result<(), Error> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name() == Some("new".as_ref()) && path.join("benchmark.json").is_file() {
+            found.push(path);
+        } else {
+            find_criterion_benchmarks(&path, found)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads one benchmark of a criterion output directory. Criterion saves all
+/// times in nanoseconds.
+fn criterion_dir_result(dir: &Path) -> Result<BenchResult, Error> {
+    let read = |name: &str| -> Result<BufReader<File>, Error> {
+        Ok(BufReader::new(File::open(dir.join(name))?))
+    };
+    let benchmark: SavedBenchmark = serde_json::from_reader(read("benchmark.json")?)?;
+    let estimates: SavedEstimates = serde_json::from_reader(read("estimates.json")?)?;
+    let sample: SavedSample = serde_json::from_reader(read("sample.json")?)?;
+
+    let BenchKey {
+        curve,
+        operation,
+        size,
+    } = parse_benchmark_id(&benchmark.full_id)?;
+    let per_iteration: Vec<f64> = sample
+        .times
+        .iter()
+        .zip(&sample.iters)
+        .map(|(time, iterations)| time / iterations * 1e-9)
+        .collect();
+    let sorted = ascending(&per_iteration);
+    let seconds = Seconds {
+        mean: estimates.mean.point_estimate * 1e-9,
+        median: estimates.median.point_estimate * 1e-9,
+        std_dev: estimates.std_dev.point_estimate * 1e-9,
+        median_abs_dev: Some(estimates.median_abs_dev.point_estimate * 1e-9),
+        min: sorted.first().copied(),
+        max: sorted.last().copied(),
+    };
+    Ok(BenchResult {
+        outliers: Some(outliers(&sorted)),
+        ..BenchResult::new(curve, operation, size, seconds, sorted.len())
+    })
+}
+
+/// The `benchmark.json` that criterion saves with every benchmark.
+#[derive(Deserialize)]
+struct SavedBenchmark {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct PointEstimate {
+    point_estimate: f64,
+}
+
+/// The subset of criterion's `estimates.json` that we consume.
+#[derive(Deserialize)]
+struct SavedEstimates {
+    mean: PointEstimate,
+    median: PointEstimate,
+    median_abs_dev: PointEstimate,
+    std_dev: PointEstimate,
+}
+
+/// Criterion's `sample.json`: the total time of each sample and its number of
+/// iterations.
+#[derive(Deserialize)]
+struct SavedSample {
+    iters: Vec<f64>,
+    times: Vec<f64>,
+}
+
+fn to_seconds(value: f64, unit: &str) -> Result<f64, Error> {
+    let scale = match unit {
+        "ps" => 1e-12,
+        "ns" => 1e-9,
+        "us" | "µs" => 1e-6,
+        "ms" => 1e-3,
+        "s" => 1.0,
+        _ => return Err(Error::Unit(unit.to_string())),
+    };
+    Ok(value * scale)
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    estimate: f64,
+    unit: String,
+}
+
+/// The subset of a cargo-criterion message that we consume.
+#[derive(Deserialize)]
+struct CriterionMessage {
+    reason: String,
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    unit: String,
+    #[serde(default)]
+    iteration_count: Vec<f64>,
+    #[serde(default)]
+    measured_values: Vec<f64>,
+    mean: Option<Estimate>,
+    median: Option<Estimate>,
+    median_abs_dev: Option<Estimate>,
+}
+
+impl CriterionMessage {
+    fn into_result(self) -> Result<BenchResult, Error> {
+        let BenchKey {
+            curve,
+            operation,
+            size,
+        } = parse_benchmark_id(&self.id)?;
+        let (mean, median) = match (&self.mean, &self.median) {
+            (Some(mean), Some(median)) => (
+                to_seconds(mean.estimate, &mean.unit)?,
+                to_seconds(median.estimate, &median.unit)?,
+            ),
+            _ => return Err(Error::BenchmarkId(self.id)),
+        };
+
+        // Criterion reports the total time of each sample, so normalize by
+        // the sample's iteration count before taking the deviation.
+        let per_iteration: Vec<f64> = self
+            .measured_values
+            .iter()
+            .zip(&self.iteration_count)
+            .map(|(value, iterations)| to_seconds(value / iterations, &self.unit))
+            .collect::<Result<_, _>>()?;
+
+        let median_abs_dev = self
+            .median_abs_dev
+            .map(|mad| to_seconds(mad.estimate, &mad.unit))
+            .transpose()?;
+
+        let sorted = ascending(&per_iteration);
+        let seconds = Seconds {
+            mean,
+            median,
+            std_dev: std_dev(&sorted),
+            median_abs_dev,
+            min: sorted.first().copied(),
+            max: sorted.last().copied(),
+        };
+        Ok(BenchResult {
+            outliers: Some(outliers(&sorted)),
+            ..BenchResult::new(curve, operation, size, seconds, sorted.len())
+        })
+    }
+}
+
+fn ascending(values: &[f64]) -> Vec<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// The `p`-th percentile of non-empty `sorted` values, interpolating between
+/// neighbors like criterion does.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let floor = rank.floor() as usize;
+    match sorted.get(floor + 1) {
+        Some(ceiling) => sorted[floor] + (ceiling - sorted[floor]) * rank.fract(),
+        None => sorted[floor],
+    }
+}
+
+/// The median absolute deviation of `sorted` values around `median`, scaled
+/// like criterion's to estimate the standard deviation of normal data.
+fn median_abs_dev(sorted: &[f64], median: f64) -> f64 {
+    let deviations = ascending(
+        &sorted
+            .iter()
+            .map(|v| (v - median).abs())
+            .collect::<Vec<_>>(),
+    );
+    percentile(&deviations, 50.0) * 1.4826
+}
+
+/// The number of `sorted` values outside Tukey's fences, the mild and severe
+/// outliers of criterion's classification.
+fn outliers(sorted: &[f64]) -> usize {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let (q1, q3) = (percentile(sorted, 25.0), percentile(sorted, 75.0));
+    let iqr = q3 - q1;
+    let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+    sorted.iter().filter(|&&v| v < low || v > high).count()
+}
+
+/// The sample standard deviation of `values`.
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(point_estimate: f64) -> serde_json::Value {
+        serde_json::json!({
+            "confidence_interval": {
+                "confidence_level": 0.95,
+                "lower_bound": point_estimate * 0.9,
+                "upper_bound": point_estimate * 1.1,
+            },
+            "point_estimate": point_estimate,
+            "standard_error": 0.1,
+        })
+    }
+
+    fn write_benchmark(dir: &Path, full_id: &str, per_iteration_ns: &[f64]) {
+        fs::create_dir_all(dir).unwrap();
+        let benchmark = serde_json::json!({
+            "group_id": "bls12_381/msm",
+            "function_id": "G1",
+            "value_str": "1024",
+            "throughput": null,
+            "full_id": full_id,
+            "directory_name": "bls12_381_msm/G1/1024",
+            "title": full_id,
+        });
+        let estimates = serde_json::json!({
+            "mean": estimate(22.6),
+            "median": estimate(14.5),
+            "median_abs_dev": estimate(3.7),
+            "slope": null,
+            "std_dev": estimate(26.9),
+        });
+        // Criterion saves the total time of samples of varying iterations
+        let iters: Vec<f64> = (0..per_iteration_ns.len())
+            .map(|i| (i % 2 + 1) as f64)
+            .collect();
+        let times: Vec<f64> = per_iteration_ns
+            .iter()
+            .zip(&iters)
+            .map(|(t, i)| t * i)
+            .collect();
+        let sample = serde_json::json!({
+            "sampling_mode": "Linear",
+            "iters": iters,
+            "times": times,
+        });
+        for (name, value) in [
+            ("benchmark.json", benchmark),
+            ("estimates.json", estimates),
+            ("sample.json", sample),
+        ] {
+            fs::write(dir.join(name), value.to_string()).unwrap();
+        }
+    }
+
+    #[test]
+    fn criterion_dir_spread() {
+        let root = std::env::temp_dir().join(format!("zkalc-criterion-{}", std::process::id()));
+        let bench = root.join("bls12_381_msm").join("G1").join("1024");
+        let per_iteration = [10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 100.0];
+        write_benchmark(&bench.join("new"), "bls12_381/msm/G1/1024", &per_iteration);
+        // A saved baseline of the same benchmark is not collected again
+        write_benchmark(&bench.join("base"), "bls12_381/msm/G1/1024", &per_iteration);
+
+        let results = Results::from_criterion_dir(&root);
+        fs::remove_dir_all(&root).unwrap();
+        let results = results.unwrap();
+
+        assert_eq!(results.results.len(), 1);
+        let r = &results.results[0];
+        assert_eq!(r.key(), ("bls12_381", "msm_G1", Some(1024)));
+        assert_eq!(r.samples, 10);
+        assert_eq!(r.outliers, Some(1));
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-15;
+        assert!(close(r.seconds.mean, 22.6e-9));
+        assert!(close(r.seconds.median, 14.5e-9));
+        assert!(close(r.seconds.std_dev, 26.9e-9));
+        assert!(close(r.seconds.median_abs_dev.unwrap(), 3.7e-9));
+        assert!(close(r.seconds.min.unwrap(), 10e-9));
+        assert!(close(r.seconds.max.unwrap(), 100e-9));
+    }
+
+    #[test]
+    fn overhead_is_subtracted_from_cheap_operations() {
+        let result = |operation: &str, size, mean| {
+            let seconds = Seconds {
+                mean,
+                median: mean,
+                std_dev: 1e-10,
+                median_abs_dev: None,
+                min: Some(mean),
+                max: Some(mean),
+            };
+            BenchResult::new("bn254".into(), operation.into(), size, seconds, 100)
+        };
+        let mut results = Results::new(vec![
+            result("noop", None, 1e-9),
+            result("add_ff", None, 5e-9),
+            result("pairing", None, 1e-3),
+            result("msm_G1", Some(2), 5e-9),
+        ]);
+        results.subtract_overhead();
+        results.subtract_overhead();
+
+        let r = &results.results;
+        assert_eq!(r[0].raw_seconds, None);
+        assert!((r[1].seconds.mean - 4e-9).abs() < 1e-20);
+        assert!((r[1].seconds.max.unwrap() - 4e-9).abs() < 1e-20);
+        assert_eq!(r[1].seconds.std_dev, 1e-10);
+        assert_eq!(r[1].raw_seconds.as_ref().unwrap().mean, 5e-9);
+        assert!((r[1].ops_per_second.unwrap() - 2.5e8).abs() < 1.0);
+        // Expensive and sized operations are kept as measured
+        assert_eq!(r[2].seconds.mean, 1e-3);
+        assert_eq!(r[2].raw_seconds, None);
+        assert_eq!(r[3].seconds.mean, 5e-9);
+    }
+
+    #[test]
+    fn operations_as_cheap_as_the_overhead_are_not_calibrated() {
+        let result = |operation: &str, mean| {
+            let seconds = Seconds {
+                mean,
+                median: mean,
+                std_dev: 1e-10,
+                median_abs_dev: None,
+                min: Some(mean),
+                max: Some(mean),
+            };
+            BenchResult::new("bn254".into(), operation.into(), None, seconds, 100)
+        };
+        let mut results = Results::new(vec![
+            result("noop", 1e-9),
+            result("neg_ff", 1e-9),
+            result("is_identity_G1", 0.9e-9),
+        ]);
+        results.subtract_overhead();
+
+        for r in &results.results[1..] {
+            assert_eq!(r.raw_seconds, None);
+            assert!(r.seconds.median > 0.0);
+            assert!(r.ops_per_second.unwrap().is_finite());
+        }
+    }
+
+    #[test]
+    fn migrates_unversioned_files() {
+        let v1 = serde_json::json!({
+            "results": [
+                {
+                    "curve": "bls12_381",
+                    "operation": "msm_G1",
+                    "size": 1024,
+                    "seconds": { "mean": 2e-3, "median": 1.9e-3, "std_dev": 1e-5 },
+                    "samples": 100,
+                    "peak_bytes": 4096
+                },
+                {
+                    "machine": "laptop",
+                    "curve": "bls12_381",
+                    "operation": "pairing",
+                    "seconds": { "mean": 1e-3, "median": 1e-3, "std_dev": 1e-6 },
+                    "samples": 50
+                }
+            ]
+        });
+        let results = migrate(v1).unwrap();
+        assert_eq!(results.schema_version, SCHEMA_VERSION);
+        assert_eq!(results.machine, None);
+
+        let r = &results.results;
+        assert_eq!(r.len(), 2);
+        assert_eq!(r[0].key(), ("bls12_381", "msm_G1", Some(1024)));
+        assert_eq!(r[0].machine, None);
+        assert_eq!(r[0].seconds.median, 1.9e-3);
+        assert_eq!(r[0].samples, 100);
+        assert_eq!(r[0].peak_bytes, Some(4096));
+        // The throughput that version 1 had no field for is derived
+        assert_eq!(r[0].ops_per_second, None);
+        assert!((r[0].elements_per_second.unwrap() - 512e3).abs() < 1e-6);
+        assert_eq!(r[1].key(), ("bls12_381", "pairing", None));
+        assert_eq!(r[1].machine.as_deref(), Some("laptop"));
+        assert!((r[1].ops_per_second.unwrap() - 1e3).abs() < 1e-9);
+        assert_eq!(r[1].elements_per_second, None);
+        assert_eq!(r[1].outliers, None);
+        assert_eq!(r[1].raw_seconds, None);
+    }
+
+    #[test]
+    fn rejects_newer_schema_versions() {
+        let newer = serde_json::json!({
+            "schema_version": SCHEMA_VERSION + 1,
+            "results": [],
+        });
+        assert!(matches!(
+            migrate(newer),
+            Err(Error::SchemaVersion(version)) if version == SCHEMA_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn current_schema_version_is_read_unchanged() {
+        let seconds = Seconds {
+            mean: 2e-3,
+            median: 1.9e-3,
+            std_dev: 1e-5,
+            median_abs_dev: Some(1e-6),
+            min: Some(1.8e-3),
+            max: Some(2.5e-3),
+        };
+        let mut results = Results::new(vec![BenchResult {
+            outliers: Some(3),
+            peak_bytes: Some(4096),
+            ..BenchResult::new(
+                "bls12_381".into(),
+                "msm_G1".into(),
+                Some(1024),
+                seconds,
+                100,
+            )
+        }]);
+        results.machine = Some(MachineInfo {
+            cpu: "Example CPU".into(),
+            cores: Some(8),
+            target: "x86_64-unknown-linux-gnu".into(),
+            release: true,
+            allocator: None,
+        });
+
+        let mut file = Vec::new();
+        results.to_writer(&mut file).unwrap();
+        assert_eq!(Results::from_reader(file.as_slice()).unwrap(), results);
+    }
+
+    fn unsized_result(curve: &str, operation: &str, median: f64) -> BenchResult {
+        let seconds = Seconds {
+            mean: median,
+            median,
+            std_dev: 0.0,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        BenchResult::new(curve.into(), operation.into(), None, seconds, 100)
+    }
+
+    #[test]
+    fn merge_labels_results_with_their_machine() {
+        let mut laptop = Results::new(vec![unsized_result("bls12_381", "pairing", 1e-3)]);
+        laptop.machine = Some(MachineInfo::detect());
+        let server = Results::new(vec![
+            unsized_result("bls12_381", "pairing", 5e-4),
+            unsized_result("bn254", "mul_ff", 1e-8),
+        ]);
+
+        let merged = Results::merge([
+            ("laptop".to_string(), laptop),
+            ("server".to_string(), server),
+        ])
+        .unwrap();
+        assert_eq!(merged.schema_version, SCHEMA_VERSION);
+        assert_eq!(merged.machine, None);
+        let labelled: Vec<_> = merged
+            .results
+            .iter()
+            .map(|r| (r.machine.as_deref(), r.curve.as_str(), r.seconds.median))
+            .collect();
+        assert_eq!(
+            labelled,
+            [
+                (Some("laptop"), "bls12_381", 1e-3),
+                (Some("server"), "bls12_381", 5e-4),
+                (Some("server"), "bn254", 1e-8),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_rejects_duplicate_machines() {
+        let run = || Results::new(vec![unsized_result("bls12_381", "pairing", 1e-3)]);
+        let merged = Results::merge([
+            ("laptop".to_string(), run()),
+            ("server".to_string(), run()),
+            ("laptop".to_string(), run()),
+        ]);
+        assert!(matches!(merged, Err(Error::DuplicateMachine(label)) if label == "laptop"));
+    }
+
+    #[test]
+    fn csv_quotes_fields_and_leaves_missing_sizes_empty() {
+        let seconds = Seconds {
+            mean: 2e-3,
+            median: 1.5e-3,
+            std_dev: 1e-5,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        let results = Results::new(vec![
+            BenchResult::new(
+                "bls12_381".into(),
+                "msm_G1".into(),
+                Some(1024),
+                seconds.clone(),
+                10,
+            ),
+            unsized_result("bls12_381", "pairing", 1e-3),
+            unsized_result("a,b", "say \"hi\"", 1e-3),
+            unsized_result("bn254", "two\nlines", 1e-3),
+        ]);
+
+        let mut csv = Vec::new();
+        results.to_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "curve,operation,size,mean_seconds,median_seconds,stddev_seconds\n\
+             bls12_381,msm_G1,1024,2e-3,1.5e-3,1e-5\n\
+             bls12_381,pairing,,1e-3,1e-3,0e0\n\
+             \"a,b\",\"say \"\"hi\"\"\",,1e-3,1e-3,0e0\n\
+             bn254,\"two\nlines\",,1e-3,1e-3,0e0\n"
+        );
+    }
+
+    #[test]
+    fn parses_criterion_json_messages() {
+        // Abridged from `cargo criterion --message-format=json`
+        let messages = r#"{"reason":"benchmark-complete","id":"bls12_381/msm/G1/1024","report_directory":"target/criterion/reports/bls12_381_msm/G1/1024","iteration_count":[1.0,2.0,3.0],"measured_values":[1000000.0,2200000.0,3000000.0],"unit":"ns","throughput":[{"per_iteration":1024,"unit":"elements"}],"typical":{"estimate":1.05,"lower_bound":1.0,"upper_bound":1.1,"unit":"ms"},"mean":{"estimate":1.05,"lower_bound":1.0,"upper_bound":1.1,"unit":"ms"},"median":{"estimate":1.0,"lower_bound":0.99,"upper_bound":1.01,"unit":"ms"},"median_abs_dev":{"estimate":20.0,"lower_bound":10.0,"upper_bound":30.0,"unit":"us"},"slope":null,"change":null}
+
+{"reason":"group-complete","group_name":"bls12_381/msm","benchmarks":["bls12_381/msm/G1/1024"],"report_directory":"target/criterion/reports/bls12_381_msm"}
+{"reason":"benchmark-complete","id":"bls12_381/pairing","report_directory":"target/criterion/reports/bls12_381/pairing","iteration_count":[10.0,20.0],"measured_values":[5000.0,10000.0],"unit":"us","throughput":[],"typical":{"estimate":500.0,"lower_bound":490.0,"upper_bound":510.0,"unit":"us"},"mean":{"estimate":500.0,"lower_bound":490.0,"upper_bound":510.0,"unit":"us"},"median":{"estimate":500.0,"lower_bound":490.0,"upper_bound":510.0,"unit":"us"},"median_abs_dev":null,"slope":null,"change":null}
+"#;
+        let results = Results::from_criterion_messages(messages.as_bytes()).unwrap();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+        let r = &results.results;
+        assert_eq!(r.len(), 2);
+        assert_eq!(r[0].key(), ("bls12_381", "msm_G1", Some(1024)));
+        assert_eq!(r[0].samples, 3);
+        assert!(close(r[0].seconds.mean, 1.05e-3));
+        assert!(close(r[0].seconds.median, 1e-3));
+        assert!(close(r[0].seconds.median_abs_dev.unwrap(), 2e-5));
+        // Per iteration, the samples took 1, 1.1 and 1 ms
+        assert!(close(r[0].seconds.min.unwrap(), 1e-3));
+        assert!(close(r[0].seconds.max.unwrap(), 1.1e-3));
+        assert!(close(r[0].seconds.std_dev, 0.1e-3 / 3f64.sqrt()));
+        assert!((r[0].elements_per_second.unwrap() - 1024.0 / 1.05e-3).abs() < 1e-6);
+
+        assert_eq!(r[1].key(), ("bls12_381", "pairing", None));
+        assert_eq!(r[1].samples, 2);
+        assert!(close(r[1].seconds.mean, 5e-4));
+        assert_eq!(r[1].seconds.median_abs_dev, None);
+        assert!(close(r[1].seconds.std_dev, 0.0));
+        assert!((r[1].ops_per_second.unwrap() - 2e3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_samples_needs_samples() {
+        let r = BenchResult::from_samples("bls12_381/pairing", &[3e-3, 1e-3, 2e-3]).unwrap();
+        assert_eq!(r.key(), ("bls12_381", "pairing", None));
+        assert_eq!(r.samples, 3);
+        assert_eq!(r.seconds.median, 2e-3);
+        assert!(matches!(
+            BenchResult::from_samples("bls12_381/pairing", &[]),
+            Err(Error::NoSamples(id)) if id == "bls12_381/pairing"
+        ));
+    }
+
+    #[test]
+    fn outliers_use_tukey_fences() {
+        assert_eq!(outliers(&[]), 0);
+        assert_eq!(outliers(&[1.0, 2.0, 3.0, 4.0]), 0);
+        // Quartiles 12.25 and 16.75 put the fences at 5.5 and 23.5
+        let sorted = [1.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 100.0];
+        assert_eq!(outliers(&sorted), 2);
+    }
+}
@@ -5,10 +5,10 @@ use criterion::{black_box, BenchmarkId, Criterion};
 use curve25519_dalek::ristretto::RistrettoPoint;
 use curve25519_dalek::scalar::Scalar;
 use curve25519_dalek::traits::MultiscalarMul;
-use rand::rngs::OsRng;
+use zkalc_common::config;
 
 fn bench_add_ff(c: &mut Criterion) {
-    let mut rng = OsRng;
+    let mut rng = config::rng();
     c.bench_function("curve25519/add_ff", |b| {
         let lhs = Scalar::random(&mut rng);
         let rhs = Scalar::random(&mut rng);
@@ -17,16 +17,15 @@ fn bench_add_ff(c: &mut Criterion) {
 }
 
 fn bench_invert_ff(c: &mut Criterion) {
-    let mut rng = OsRng;
+    let mut rng = config::rng();
     c.bench_function("curve25519/invert", |b| {
-        let lhs = Scalar::random(&mut rng);
-        let rhs = Scalar::random(&mut rng);
-        b.iter(|| black_box(lhs) + black_box(rhs))
+        let a = Scalar::random(&mut rng);
+        b.iter(|| black_box(a).invert())
     });
 }
 
 fn bench_mul_ec(c: &mut Criterion) {
-    let mut rng = OsRng;
+    let mut rng = config::rng();
     c.bench_function("curve25519/mul_G1", |b| {
         let lhs = RistrettoPoint::random(&mut rng);
         let rhs = Scalar::random(&mut rng);
@@ -35,7 +34,7 @@ fn bench_mul_ec(c: &mut Criterion) {
 }
 
 fn bench_mul_ff(c: &mut Criterion) {
-    let mut rng = OsRng;
+    let mut rng = config::rng();
     c.bench_function("curve25519/mul_ff", |b| {
         let lhs = Scalar::random(&mut rng);
         let rhs = Scalar::random(&mut rng);
@@ -44,7 +43,7 @@ fn bench_mul_ff(c: &mut Criterion) {
 }
 
 fn bench_msm(c: &mut Criterion) {
-    let mut rng = OsRng;
+    let mut rng = config::rng();
     for logsize in 1..=21 {
         let mut group = c.benchmark_group("curve25519/msm");
         let size = 1 << logsize;
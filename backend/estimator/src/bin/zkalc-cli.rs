@@ -0,0 +1,157 @@
+//! Estimates the cost of an operation from a results file (see
+//! `zkalc_common::results`), for scripts and CI.
+//!
+//! Usage:
+//!
+//!     zkalc-cli estimate RESULTS_JSON CURVE OPERATION [SIZE] [--machine MACHINE]
+//!
+//! e.g. `zkalc-cli estimate blstrs.json bls12_381 msm_G1 1000000`. Sized
+//! operations are fitted over all their measured sizes, with the Pippenger
+//! model for the G1 and G2 MSMs and the linear model otherwise, including the
+//! pairing product `msm_Gt`, and evaluated at `SIZE`. A size that was measured,
+//! and an operation without sizes, are answered with the measured median
+//! instead.
+//!
+//! Only the results of one machine are used. `--machine` picks it in a file
+//! merged from several machines, and is not needed when there is only one.
+
+use std::process::ExitCode;
+
+use zkalc_common::report::format_seconds;
+use zkalc_common::results::{BenchResult, Results};
+use zkalc_estimator::{fit_linear, fit_msm};
+
+const USAGE: &str = "usage:
+    zkalc-cli estimate RESULTS_JSON CURVE OPERATION [SIZE] [--machine MACHINE]";
+
+type CmdResult = Result<(), Box<dyn std::error::Error>>;
+
+/// The results of `machine`, or of the only machine in `results` without one.
+fn machine_results<'a>(
+    results: &'a Results,
+    path: &str,
+    machine: Option<&str>,
+) -> Result<Vec<&'a BenchResult>, String> {
+    let mut machines: Vec<_> = results
+        .results
+        .iter()
+        .filter_map(|r| r.machine.as_deref())
+        .collect();
+    machines.sort_unstable();
+    machines.dedup();
+    match machine {
+        Some(machine) if !machines.contains(&machine) => Err(format!(
+            "no machine '{}' in {}, only {}",
+            machine,
+            path,
+            machines.join(", ")
+        )),
+        Some(machine) => Ok(results
+            .results
+            .iter()
+            .filter(|r| r.machine.as_deref() == Some(machine))
+            .collect()),
+        None if machines.len() > 1 => Err(format!(
+            "{} has results of the machines {}: pick one with --machine",
+            path,
+            machines.join(", ")
+        )),
+        None => Ok(results.results.iter().collect()),
+    }
+}
+
+fn estimate(args: &[String]) -> CmdResult {
+    let mut positional = Vec::new();
+    let mut machine = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--machine" => machine = Some(args.next().ok_or(USAGE)?.as_str()),
+            _ => positional.push(arg),
+        }
+    }
+    let (path, curve, operation, size) = match positional[..] {
+        [path, curve, operation] => (path, curve, operation, None),
+        [path, curve, operation, size] => (path, curve, operation, Some(size.parse::<usize>()?)),
+        _ => return Err(USAGE.into()),
+    };
+    let results = Results::open(path)?;
+    let results = machine_results(&results, path, machine)?;
+
+    let measured: Vec<_> = results
+        .iter()
+        .filter(|r| &r.curve == curve && &r.operation == operation)
+        .collect();
+    if measured.is_empty() {
+        let mut operations: Vec<_> = results
+            .iter()
+            .filter(|r| &r.curve == curve)
+            .map(|r| r.operation.as_str())
+            .collect();
+        if operations.is_empty() {
+            let mut curves: Vec<_> = results.iter().map(|r| r.curve.as_str()).collect();
+            curves.sort_unstable();
+            curves.dedup();
+            return Err(format!(
+                "no curve '{}' in {}, only {}",
+                curve,
+                path,
+                curves.join(", ")
+            )
+            .into());
+        }
+        operations.sort_unstable();
+        operations.dedup();
+        return Err(format!(
+            "no operation '{}' for {} in {}, only {}",
+            operation,
+            curve,
+            path,
+            operations.join(", ")
+        )
+        .into());
+    }
+
+    let samples: Vec<(usize, f64)> = measured
+        .iter()
+        .filter_map(|r| Some((r.size? as usize, r.seconds.median)))
+        .collect();
+    let seconds = match (size, samples.is_empty()) {
+        (None, true) => measured[0].seconds.median,
+        (Some(_), true) => {
+            return Err(format!("{} takes no size on {}", operation, curve).into());
+        }
+        (None, false) => return Err(format!("{} needs a SIZE", operation).into()),
+        (Some(n), false) => match samples.iter().find(|&&(m, _)| m == n) {
+            Some(&(_, seconds)) => seconds,
+            None if samples.iter().all(|&(m, _)| m == samples[0].0) => {
+                return Err(format!(
+                    "{} was only measured at size {}: nothing to fit",
+                    operation, samples[0].0
+                )
+                .into());
+            }
+            None if matches!(operation.as_str(), "msm_G1" | "msm_G2") => {
+                fit_msm(&samples).estimate(n)
+            }
+            None => fit_linear(&samples).estimate(n),
+        },
+    };
+    println!("{}", format_seconds(seconds));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("estimate") => estimate(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("zkalc-cli: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Cost models fitted to benchmark data.
+//!
+//! Benchmarks only cover a handful of input sizes. The models here are fitted
+//! to those measurements with least squares so that the cost of any other size
+//! can be estimated, without every consumer reimplementing the interpolation.
+//!
+//! Samples are `(size, seconds)` pairs, as found in the results files written
+//! by `zkalc-results`.
+//!
+//! [`normalize`] instead expresses the measured costs in field multiplications.
+
+pub mod normalize;
+
+/// The cost model of a Pippenger multi-scalar multiplication:
+/// `t(n) = coefficient * n / log2(n) + intercept`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MsmModel {
+    pub coefficient: f64,
+    pub intercept: f64,
+    /// The coefficient of determination of the fit, at most 1.
+    pub r_squared: f64,
+}
+
+impl MsmModel {
+    pub fn estimate(&self, n: usize) -> f64 {
+        self.coefficient * msm_feature(n) + self.intercept
+    }
+}
+
+/// The cost model of an operation that does constant work per element, such as
+/// a batch of additions: `t(n) = coefficient * n + intercept`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearModel {
+    pub coefficient: f64,
+    pub intercept: f64,
+    /// The coefficient of determination of the fit, at most 1.
+    pub r_squared: f64,
+}
+
+impl LinearModel {
+    pub fn estimate(&self, n: usize) -> f64 {
+        self.coefficient * n as f64 + self.intercept
+    }
+}
+
+/// Estimates the nanoseconds of a `bits`-bit double-and-add scalar
+/// multiplication from the cost of a group addition and of a doubling: one
+/// doubling per bit, and one addition for the half of the bits of a random
+/// scalar that are set.
+///
+/// This models the textbook algorithm, e.g. benchmark
+/// `scalar_mul/G1/double_and_add`, not the windowed or GLV multiplications of
+/// the backends, so it is an upper bound for curves where only the group
+/// primitives were measured.
+pub fn scalar_mul_from_primitives(add_ns: f64, double_ns: f64, bits: u32) -> f64 {
+    let bits = bits as f64;
+    bits * double_ns + bits / 2.0 * add_ns
+}
+
+/// `n / log2(n)`, with the logarithm clamped to 1 so that tiny MSMs stay finite.
+fn msm_feature(n: usize) -> f64 {
+    let n = n as f64;
+    n / n.log2().max(1.0)
+}
+
+/// Fits an [`MsmModel`] to `samples`.
+///
+/// # Panics
+///
+/// Panics if `samples` has fewer than two distinct sizes.
+pub fn fit_msm(samples: &[(usize, f64)]) -> MsmModel {
+    let (coefficient, intercept, r_squared) =
+        least_squares(samples.iter().map(|&(n, t)| (msm_feature(n), t)));
+    MsmModel {
+        coefficient,
+        intercept,
+        r_squared,
+    }
+}
+
+/// Fits a [`LinearModel`] to `samples`.
+///
+/// # Panics
+///
+/// Panics if `samples` has fewer than two distinct sizes.
+pub fn fit_linear(samples: &[(usize, f64)]) -> LinearModel {
+    let (coefficient, intercept, r_squared) =
+        least_squares(samples.iter().map(|&(n, t)| (n as f64, t)));
+    LinearModel {
+        coefficient,
+        intercept,
+        r_squared,
+    }
+}
+
+/// Ordinary least squares of `y = slope * x + intercept`, returning the slope,
+/// the intercept and R².
+fn least_squares(points: impl Iterator<Item = (f64, f64)> + Clone) -> (f64, f64, f64) {
+    let n = points.clone().count() as f64;
+    let mean_x = points.clone().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.clone().map(|(_, y)| y).sum::<f64>() / n;
+
+    let sxx: f64 = points.clone().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points
+        .clone()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    assert!(sxx > 0.0, "the fit needs at least two distinct sizes");
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+
+    let total: f64 = points.clone().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    let residual: f64 = points
+        .map(|(x, y)| (y - (slope * x + intercept)).powi(2))
+        .sum();
+    // A constant `y` is fitted exactly by a flat line.
+    let r_squared = if total == 0.0 {
+        1.0
+    } else {
+        1.0 - residual / total
+    };
+
+    (slope, intercept, r_squared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkalc_common::results::Results;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-9 * b.abs().max(1.0), "{} != {}", a, b);
+    }
+
+    #[test]
+    fn fit_msm_recovers_coefficients() {
+        let model = MsmModel {
+            coefficient: 3e-6,
+            intercept: 2e-4,
+            r_squared: 1.0,
+        };
+        let samples: Vec<_> = (1..=20)
+            .map(|logsize| 1 << logsize)
+            .map(|n| (n, model.estimate(n)))
+            .collect();
+
+        let fitted = fit_msm(&samples);
+        assert_close(fitted.coefficient, model.coefficient);
+        assert_close(fitted.intercept, model.intercept);
+        assert_close(fitted.r_squared, 1.0);
+        assert_close(fitted.estimate(3 << 20), model.estimate(3 << 20));
+    }
+
+    #[test]
+    fn fit_linear_recovers_coefficients() {
+        let samples: Vec<_> = (1..=100).map(|n| (n, 5e-9 * n as f64 + 1e-7)).collect();
+
+        let fitted = fit_linear(&samples);
+        assert_close(fitted.coefficient, 5e-9);
+        assert_close(fitted.intercept, 1e-7);
+        assert_close(fitted.r_squared, 1.0);
+        assert_close(fitted.estimate(1000), 5e-9 * 1000.0 + 1e-7);
+    }
+
+    #[test]
+    fn noisy_data_lowers_r_squared() {
+        // Alternate between +/-10% around a linear cost.
+        let samples: Vec<_> = (1..=100)
+            .map(|n| {
+                let noise = if n % 2 == 0 { 1.1 } else { 0.9 };
+                (n, 1e-6 * n as f64 * noise)
+            })
+            .collect();
+
+        let fitted = fit_linear(&samples);
+        assert!(fitted.r_squared < 1.0);
+        assert!(fitted.r_squared > 0.9);
+        assert!((fitted.coefficient - 1e-6).abs() < 1e-7);
+    }
+
+    #[test]
+    fn scalar_mul_model_bounds_a_recorded_run() {
+        // `bench_blstrs` run of `add_G1`, `doubling/G1` and
+        // `scalar_mul/G1/double_and_add`, collected with `zkalc-results collect`
+        let fixture = include_bytes!("../fixtures/blstrs_scalar_mul_g1.json");
+        let results = Results::from_reader(&fixture[..]).unwrap();
+        let median_ns = |operation: &str| {
+            let result = results.results.iter().find(|r| r.operation == operation);
+            result.unwrap().seconds.median * 1e9
+        };
+
+        let predicted =
+            scalar_mul_from_primitives(median_ns("add_G1"), median_ns("doubling_G1"), 255);
+        let measured = median_ns("scalar_mul_G1_double_and_add");
+        // An upper bound, as the leading doublings and the first addition are
+        // of the identity, but a close one
+        assert!(
+            measured <= predicted && predicted < 1.2 * measured,
+            "predicted {} ns, measured {} ns",
+            predicted,
+            measured
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fit_needs_two_sizes() {
+        fit_linear(&[(8, 1.0), (8, 2.0)]);
+    }
+}
@@ -0,0 +1,159 @@
+//! Costs relative to a scalar field multiplication.
+//!
+//! Dividing every median by the median of `mul_ff` on the same curve and
+//! machine gives unitless costs, e.g. an inversion of about 60
+//! multiplications, that carry over between machines better than seconds do.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use zkalc_common::results::Results;
+
+/// The operation that costs are normalized to.
+pub const MUL_OPERATION: &str = "mul_ff";
+
+/// The cost of a benchmark in scalar field multiplications.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedResult {
+    pub machine: Option<String>,
+    pub curve: String,
+    pub operation: String,
+    pub size: Option<u64>,
+    /// The median over the median of [`MUL_OPERATION`].
+    pub muls: f64,
+}
+
+/// The results of a file, in the order of the file, normalized by
+/// [`normalize_to_mul`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedResults {
+    pub results: Vec<NormalizedResult>,
+}
+
+/// A curve with results but no [`MUL_OPERATION`] to normalize them to, or
+/// only one that took no time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingMul {
+    pub machine: Option<String>,
+    pub curve: String,
+}
+
+impl fmt::Display for MissingMul {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no {} result for {}", MUL_OPERATION, self.curve)?;
+        if let Some(machine) = &self.machine {
+            write!(f, " on machine '{}'", machine)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MissingMul {}
+
+/// Divides the median of every result by the median of [`MUL_OPERATION`]
+/// without a size, for the same curve and machine.
+///
+/// Fails on the first curve that has no such measurement, rather than
+/// leaving its results out.
+pub fn normalize_to_mul(results: &Results) -> Result<NormalizedResults, MissingMul> {
+    let mul: HashMap<_, _> = results
+        .results
+        .iter()
+        .filter(|r| r.operation == MUL_OPERATION && r.size.is_none() && r.seconds.median > 0.0)
+        .map(|r| ((&r.machine, &r.curve), r.seconds.median))
+        .collect();
+
+    let results = results
+        .results
+        .iter()
+        .map(|r| {
+            let mul = mul.get(&(&r.machine, &r.curve)).ok_or_else(|| MissingMul {
+                machine: r.machine.clone(),
+                curve: r.curve.clone(),
+            })?;
+            Ok(NormalizedResult {
+                machine: r.machine.clone(),
+                curve: r.curve.clone(),
+                operation: r.operation.clone(),
+                size: r.size,
+                muls: r.seconds.median / mul,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(NormalizedResults { results })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkalc_common::results::{BenchResult, Seconds};
+
+    fn result(machine: Option<&str>, curve: &str, operation: &str, median: f64) -> BenchResult {
+        let seconds = Seconds {
+            mean: median,
+            median,
+            std_dev: 0.0,
+            median_abs_dev: None,
+            min: None,
+            max: None,
+        };
+        BenchResult {
+            machine: machine.map(String::from),
+            ..BenchResult::new(curve.into(), operation.into(), None, seconds, 100)
+        }
+    }
+
+    #[test]
+    fn divides_by_the_mul_of_the_same_curve_and_machine() {
+        let results = Results::new(vec![
+            result(None, "bls12_381", "mul_ff", 2e-8),
+            result(None, "bls12_381", "invert", 1.2e-6),
+            result(None, "bn254", "invert", 9e-7),
+            result(None, "bn254", "mul_ff", 1.5e-8),
+            result(Some("laptop"), "bn254", "mul_ff", 3e-8),
+            result(Some("laptop"), "bn254", "invert", 9e-7),
+        ]);
+
+        let normalized = normalize_to_mul(&results).unwrap();
+        let muls: Vec<_> = normalized
+            .results
+            .iter()
+            .map(|r| {
+                (
+                    r.machine.as_deref(),
+                    r.curve.as_str(),
+                    r.operation.as_str(),
+                    r.muls,
+                )
+            })
+            .collect();
+        let expected = [
+            (None, "bls12_381", "mul_ff", 1.0),
+            (None, "bls12_381", "invert", 60.0),
+            (None, "bn254", "invert", 60.0),
+            (None, "bn254", "mul_ff", 1.0),
+            (Some("laptop"), "bn254", "mul_ff", 1.0),
+            (Some("laptop"), "bn254", "invert", 30.0),
+        ];
+        assert_eq!(muls.len(), expected.len());
+        for (got, want) in muls.iter().zip(&expected) {
+            assert_eq!((got.0, got.1, got.2), (want.0, want.1, want.2));
+            assert!((got.3 - want.3).abs() < 1e-9, "{:?} != {:?}", got, want);
+        }
+    }
+
+    #[test]
+    fn fails_without_a_mul() {
+        let results = Results::new(vec![
+            result(None, "bls12_381", "mul_ff", 2e-8),
+            result(Some("laptop"), "bls12_381", "invert", 1.2e-6),
+        ]);
+        assert_eq!(
+            normalize_to_mul(&results),
+            Err(MissingMul {
+                machine: Some("laptop".into()),
+                curve: "bls12_381".into(),
+            })
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! BN254 and secp256k1 as [`zkalc_common::curve::Curve`]s, for the benchmarks
+//! shared by all backends. halo2curves calls BN254 `bn256`; the benchmark ids
+//! use `bn254` like every other backend.
+
+use ff::Field;
+use group::{Curve as _, Group};
+use halo2curves::bn256::{self, Bn256};
+use halo2curves::msm::best_multiexp;
+use halo2curves::secp256k1;
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::RngCore;
+use zkalc_common::curve::{Curve, PairingCurve};
+
+pub struct Bn254;
+
+impl Curve for Bn254 {
+    const NAME: &'static str = "bn254";
+
+    type Scalar = bn256::Fr;
+    type G1 = bn256::G1;
+    type G1Base = bn256::G1Affine;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> bn256::Fr {
+        bn256::Fr::random(rng)
+    }
+
+    fn rand_g1(rng: &mut impl RngCore) -> bn256::G1 {
+        bn256::G1::random(rng)
+    }
+
+    fn invert(x: &bn256::Fr) -> Option<bn256::Fr> {
+        x.invert().into()
+    }
+
+    fn g1_base(p: &bn256::G1) -> bn256::G1Affine {
+        p.to_affine()
+    }
+
+    fn msm_g1(bases: &[bn256::G1Affine], scalars: &[bn256::Fr]) -> bn256::G1 {
+        best_multiexp(scalars, bases)
+    }
+}
+
+impl PairingCurve for Bn254 {
+    type G2 = bn256::G2;
+    type G2Base = bn256::G2Affine;
+    type G1Affine = bn256::G1Affine;
+    type G2Affine = bn256::G2Affine;
+    type Gt = bn256::Gt;
+
+    fn rand_g2(rng: &mut impl RngCore) -> bn256::G2 {
+        bn256::G2::random(rng)
+    }
+
+    fn g2_base(p: &bn256::G2) -> bn256::G2Affine {
+        p.to_affine()
+    }
+
+    fn msm_g2(bases: &[bn256::G2Affine], scalars: &[bn256::Fr]) -> bn256::G2 {
+        best_multiexp(scalars, bases)
+    }
+
+    fn g1_to_affine(p: &bn256::G1) -> bn256::G1Affine {
+        p.to_affine()
+    }
+
+    fn g2_to_affine(p: &bn256::G2) -> bn256::G2Affine {
+        p.to_affine()
+    }
+
+    // A single-pair `multi_miller_loop`, so the G2 preparation is included.
+    fn pairing(a: &bn256::G1Affine, b: &bn256::G2Affine) -> bn256::Gt {
+        let b = bn256::G2Prepared::from(*b);
+        Bn256::multi_miller_loop(&[(a, &b)]).final_exponentiation()
+    }
+}
+
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    const NAME: &'static str = "secp256k1";
+
+    type Scalar = secp256k1::Fq;
+    type G1 = secp256k1::Secp256k1;
+    type G1Base = secp256k1::Secp256k1Affine;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> secp256k1::Fq {
+        secp256k1::Fq::random(rng)
+    }
+
+    fn rand_g1(rng: &mut impl RngCore) -> secp256k1::Secp256k1 {
+        secp256k1::Secp256k1::random(rng)
+    }
+
+    fn invert(x: &secp256k1::Fq) -> Option<secp256k1::Fq> {
+        x.invert().into()
+    }
+
+    fn g1_base(p: &secp256k1::Secp256k1) -> secp256k1::Secp256k1Affine {
+        p.to_affine()
+    }
+
+    fn msm_g1(
+        bases: &[secp256k1::Secp256k1Affine],
+        scalars: &[secp256k1::Fq],
+    ) -> secp256k1::Secp256k1 {
+        best_multiexp(scalars, bases)
+    }
+}
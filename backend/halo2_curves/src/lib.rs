@@ -0,0 +1,3 @@
+//! Support code shared by the halo2curves benchmarks.
+
+pub mod curve;
@@ -8,13 +8,21 @@ use criterion::measurement::Measurement;
 use criterion::{BenchmarkGroup, BenchmarkId};
 use ff_ce::{PrimeField};
 use pairing_ce::{bls12_381::*, bn256::*, GenericCurveProjective};  //, Engine};
+use zkalc_common::config;
 // use bellman_ce::worker::Worker;
 // use bellman_ce::source::FullDensity;
 // use bellman_ce::{multiexp::*};
 
+/// The rand 0.4 RNG that pairing_ce's `Rand` draws from, seeded with
+/// `ZKALC_SEED`. The upper two words keep the XorShift state nonzero.
+fn rng() -> XorShiftRng {
+    let seed = config::seed();
+    XorShiftRng::from_seed([seed as u32, (seed >> 32) as u32, 0x3237db17, 0xe5bc0654])
+}
+
 // Benchmark Addition in Scalar Field
 fn bench_add_ff<F: PrimeField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let rng = &mut XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+    let rng = &mut rng();
     let a: F = rng.gen();
     let b: F = rng.gen();
 
@@ -29,7 +37,7 @@ fn bench_add_ff<F: PrimeField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 
 // Benchmark Addition in Scalar FIeld
 fn bench_mul_ff<F: PrimeField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let rng = &mut XorShiftRng::from_seed([0x5dbe6259, 0x8d313d76, 0x3237db17, 0xe5bc0654]);
+    let rng = &mut rng();
     let a: F = rng.gen();
     let b: F = rng.gen();
 
@@ -44,7 +52,7 @@ fn bench_mul_ff<F: PrimeField, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 
 // Benchmark Addition in Elliptic Curve Group
 fn bench_add_ec<G: GenericCurveProjective, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = rng();
     let lhs = G::rand(&mut rng);
     let rhs = G::rand(&mut rng);
     let mut lhs = black_box(lhs);
@@ -63,7 +71,7 @@ where
     G: GenericCurveProjective,
     M: Measurement,
 {
-    let mut rng = rand::thread_rng();
+    let mut rng = rng();
     let lhs = G::rand(&mut rng);
     let rhs_scalar = <G as GenericCurveProjective>::Scalar::rand(&mut rng);
     let mut lhs = black_box(lhs);
@@ -124,7 +132,7 @@ where
 
 // Benchmark pairing
 fn bench_pairing<P: pairing_ce::Engine, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = rng();
     let a = P::G1::rand(&mut rng).into_affine();
     let b = P::G2::rand(&mut rng).into_affine();
     c.bench_function("pairing", |r| {
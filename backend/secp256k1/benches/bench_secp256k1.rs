@@ -0,0 +1,16 @@
+//! secp256k1 benchmarks on k256, with the benchmark ids of the other backends.
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use secp256k1_benchmarks::curve::Secp256k1;
+use zkalc_common::benches::{bench_field_ops, bench_group_ops};
+
+fn bench_secp256k1(c: &mut Criterion) {
+    bench_field_ops::<Secp256k1>(c);
+    bench_group_ops::<Secp256k1>(c);
+}
+
+criterion_group!(benches, bench_secp256k1);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! secp256k1 as a [`zkalc_common::curve::Curve`], for the benchmarks shared by
+//! all backends. It is not pairing-friendly, so there is no [`PairingCurve`]
+//! impl and no pairing benchmarks.
+//!
+//! [`PairingCurve`]: zkalc_common::curve::PairingCurve
+
+use k256::elliptic_curve::ops::LinearCombinationExt;
+use k256::elliptic_curve::{Field, Group};
+use k256::{ProjectivePoint, Scalar};
+use rand::RngCore;
+use zkalc_common::curve::Curve;
+
+/// The number of terms per `lincomb_ext` call of the MSM.
+const LINCOMB_CHUNK: usize = 1 << 10;
+
+pub struct Secp256k1;
+
+impl Curve for Secp256k1 {
+    const NAME: &'static str = "secp256k1";
+
+    type Scalar = Scalar;
+    type G1 = ProjectivePoint;
+    type G1Base = ProjectivePoint;
+
+    fn rand_scalar(rng: &mut impl RngCore) -> Scalar {
+        Scalar::random(rng)
+    }
+
+    fn rand_g1(rng: &mut impl RngCore) -> ProjectivePoint {
+        ProjectivePoint::random(rng)
+    }
+
+    fn invert(x: &Scalar) -> Option<Scalar> {
+        x.invert().into()
+    }
+
+    fn g1_base(p: &ProjectivePoint) -> ProjectivePoint {
+        *p
+    }
+
+    // k256 has no Pippenger, only Straus' interleaved multi-scalar
+    // multiplication, which keeps two lookup tables per term. Running it on
+    // chunks bounds that memory for the large sizes of the sweep.
+    fn msm_g1(bases: &[ProjectivePoint], scalars: &[Scalar]) -> ProjectivePoint {
+        let terms: Vec<_> = bases.iter().copied().zip(scalars.iter().copied()).collect();
+        terms
+            .chunks(LINCOMB_CHUNK)
+            .map(ProjectivePoint::lincomb_ext)
+            .sum()
+    }
+}
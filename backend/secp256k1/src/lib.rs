@@ -0,0 +1,3 @@
+//! Support code shared by the k256 benchmarks.
+
+pub mod curve;
@@ -0,0 +1,8 @@
+#!/usr/bin/env node
+
+// Runs the wasm harness under node, after `wasm-pack build --target nodejs`
+const { run } = require("./pkg/zkalc_wasm.js");
+
+const msmMaxLogsize = Number(process.env.ZKALC_MSM_MAX || 16);
+const samples = Number(process.env.ZKALC_SAMPLES || 10);
+console.log(run(msmMaxLogsize, samples));
@@ -0,0 +1,147 @@
+//! A benchmark harness for the browser (wasm32), where criterion does not run.
+//!
+//! [`run`] times the core operations of the arkworks curves with
+//! `performance.now()` and returns the results as JSON, in the schema of
+//! [`zkalc_common::results`] and with the same benchmark ids as the native
+//! backends, so that in-browser numbers can be compared with native ones
+//! directly. Build it with `wasm-pack build --target web` (or `--target
+//! nodejs` for `bench.js`) and call `run` from JS.
+
+use std::hint::black_box;
+
+use ark_ec::pairing::Pairing;
+use ark_ec::{CurveGroup, VariableBaseMSM};
+use ark_std::UniformRand;
+use wasm_bindgen::prelude::*;
+use zkalc_common::config;
+use zkalc_common::results::{BenchResult, Results};
+
+/// Browsers coarsen `performance.now()` to as much as 100µs, so every sample
+/// runs for at least this long.
+const MIN_SAMPLE_MS: f64 = 10.0;
+
+#[cfg(target_arch = "wasm32")]
+mod clock {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = performance)]
+        fn now() -> f64;
+    }
+
+    /// Milliseconds since an arbitrary origin.
+    pub fn now_ms() -> f64 {
+        now()
+    }
+}
+
+// Lets the harness be tried natively too
+#[cfg(not(target_arch = "wasm32"))]
+mod clock {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    /// Milliseconds since an arbitrary origin.
+    pub fn now_ms() -> f64 {
+        static ORIGIN: OnceLock<Instant> = OnceLock::new();
+        ORIGIN.get_or_init(Instant::now).elapsed().as_secs_f64() * 1e3
+    }
+}
+
+/// Runs the benchmarks of every curve, with MSMs of up to `2^msm_max_logsize`
+/// points and `samples` samples per benchmark, and returns the results JSON.
+///
+/// Fails, throwing in JS, if `samples` is zero.
+#[wasm_bindgen]
+pub fn run(msm_max_logsize: usize, samples: usize) -> Result<String, String> {
+    if samples == 0 {
+        return Err("at least one sample per benchmark is needed".to_string());
+    }
+    let mut results = Vec::new();
+    bench_curve::<ark_bls12_381::Bls12_381>("bls12_381", msm_max_logsize, samples, &mut results);
+    bench_curve::<ark_bn254::Bn254>("bn254", msm_max_logsize, samples, &mut results);
+
+    let mut json = Vec::new();
+    Results::new(results)
+        .to_writer(&mut json)
+        .expect("results serialize to memory");
+    Ok(String::from_utf8(json).expect("serde_json writes UTF-8"))
+}
+
+fn bench_curve<P: Pairing>(
+    name: &str,
+    msm_max_logsize: usize,
+    samples: usize,
+    results: &mut Vec<BenchResult>,
+) {
+    let mut rng = config::rng();
+    let mut record = |id: String, per_iteration: Vec<f64>| {
+        results.push(
+            BenchResult::from_samples(&id, &per_iteration).expect("ids follow the convention"),
+        );
+    };
+
+    let x = P::ScalarField::rand(&mut rng);
+    let y = P::ScalarField::rand(&mut rng);
+    record(
+        format!("{}/add_ff", name),
+        measure(samples, || black_box(x) + black_box(y)),
+    );
+    record(
+        format!("{}/mul_ff", name),
+        measure(samples, || black_box(x) * black_box(y)),
+    );
+
+    let p = P::G1::rand(&mut rng);
+    let q = P::G1::rand(&mut rng);
+    record(
+        format!("{}/add_G1", name),
+        measure(samples, || black_box(p) + black_box(q)),
+    );
+    record(
+        format!("{}/scalar_mul/G1", name),
+        measure(samples, || black_box(p) * black_box(x)),
+    );
+
+    for logsize in 1..=msm_max_logsize {
+        let size = 1 << logsize;
+        let scalars: Vec<_> = (0..size).map(|_| P::ScalarField::rand(&mut rng)).collect();
+        let bases: Vec<_> = (0..size)
+            .map(|_| P::G1::rand(&mut rng).into_affine())
+            .collect();
+        record(
+            format!("{}/msm/G1/{}", name, size),
+            measure(samples, || P::G1::msm(&bases, &scalars).unwrap()),
+        );
+    }
+
+    let a = P::G1::rand(&mut rng).into_affine();
+    let b = P::G2::rand(&mut rng).into_affine();
+    record(
+        format!("{}/pairing", name),
+        measure(samples, || P::pairing(black_box(a), black_box(b))),
+    );
+}
+
+/// Per-iteration times of `f` in seconds, one per sample.
+///
+/// The iteration count is doubled until a sample lasts [`MIN_SAMPLE_MS`],
+/// which also serves as the warm-up.
+fn measure<O>(samples: usize, mut f: impl FnMut() -> O) -> Vec<f64> {
+    let mut iterations = 1;
+    while time_ms(iterations, &mut f) < MIN_SAMPLE_MS {
+        iterations *= 2;
+    }
+    (0..samples)
+        .map(|_| time_ms(iterations, &mut f) / iterations as f64 / 1e3)
+        .collect()
+}
+
+fn time_ms<O>(iterations: u64, f: &mut impl FnMut() -> O) -> f64 {
+    let start = clock::now_ms();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    clock::now_ms() - start
+}
@@ -4,9 +4,10 @@ use criterion::{
 };
 use group::{ff::Field, Curve, Group};
 use pairing::{MillerLoopResult, MultiMillerLoop};
+use zkalc_common::config;
 
 fn bench_add_ff<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("add_ff", |b| {
         let lhs = G::Scalar::random(&mut rng);
         let rhs = G::Scalar::random(&mut rng);
@@ -15,7 +16,7 @@ fn bench_add_ff<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_mul_ff<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("mul_ff", |b| {
         let lhs = G::Scalar::random(&mut rng);
         let rhs = G::Scalar::random(&mut rng);
@@ -24,7 +25,7 @@ fn bench_mul_ff<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_invert<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("invert", |b| {
         let a = G::Scalar::random(&mut rng);
         b.iter(|| a.invert().unwrap());
@@ -32,7 +33,7 @@ fn bench_invert<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_add_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("add_G1", |r| {
         let a = G::random(&mut rng);
         let b = G::random(&mut rng);
@@ -41,7 +42,7 @@ fn bench_add_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_dbl_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("dbl_G1", |r| {
         let a = G::random(&mut rng);
         r.iter(|| a.double())
@@ -49,7 +50,7 @@ fn bench_dbl_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_mul_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("mul_G1", |b| {
         let lhs = G::random(&mut rng);
         let rhs = G::Scalar::random(&mut rng);
@@ -58,7 +59,7 @@ fn bench_mul_ec<G: Group, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
 }
 
 fn bench_pairing<P: pairing::Engine, M: Measurement>(c: &mut BenchmarkGroup<'_, M>) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     c.bench_function("pairing", |r| {
         let a = P::G1::random(&mut rng).to_affine();
         let b = P::G2::random(&mut rng).to_affine();
@@ -69,7 +70,7 @@ fn bench_pairing<P: pairing::Engine, M: Measurement>(c: &mut BenchmarkGroup<'_,
 fn bench_pairing_product<P: pairing::Engine + MultiMillerLoop, M: Measurement>(
     c: &mut BenchmarkGroup<'_, M>,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = config::rng();
     for d in 1..=10 {
         let size = 1 << d;
         let mut v: Vec<(P::G1Affine, P::G2Prepared)> = Vec::new();
@@ -79,10 +80,8 @@ fn bench_pairing_product<P: pairing::Engine + MultiMillerLoop, M: Measurement>(
             v.push((g1, g2));
         }
 
-        let mut v_ref: Vec<(&P::G1Affine, &P::G2Prepared)> = Vec::new();
-        for i in 0..size {
-            v_ref.push((&v[i].0, &v[i].1));
-        }
+        let v_ref: Vec<(&P::G1Affine, &P::G2Prepared)> =
+            v.iter().map(|(g1, g2)| (g1, g2)).collect();
 
         c.bench_with_input(BenchmarkId::new("msm/Gt", size), &d, |b, _| {
             b.iter(|| P::multi_miller_loop(&v_ref).final_exponentiation())
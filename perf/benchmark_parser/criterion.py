@@ -21,11 +21,24 @@ ark_names = {
 }
 
 op_ids = [
+    # calibration of criterion's per-iteration overhead
+    "noop",
+
     # scalar field
     "mul_ff",
     "add_ff",
+    "sub_ff",
+    "neg_ff",
+    "square_ff",
+    "sqrt_ff",
+    "is_square_ff",
     "msm_ff",
     "invert",
+    # the wide variant first, as probes match prefixes
+    "scalar_from_bytes_wide",
+    "scalar_from_bytes",
+    "scalar_to_montgomery",
+    "scalar_from_montgomery",
 
     # G1
     "mul_G1",
@@ -39,6 +52,8 @@ op_ids = [
     "add_Gt",
     "mul_Gt",
     "pairing",
+    "miller_loop",
+    "final_exponentiation",
 ]
 
 probes = {
@@ -46,7 +61,92 @@ probes = {
 
     # arkworks probes
     r'.*/msm/(G[12t]|ff)/(\d+)': lambda x, y: (f"msm_{x}", int(y)),
+    r'.*/msm/(Gt)/prepared/(\d+)': lambda x, y: (f"msm_{x}_prepared", int(y)),
+    r'.*/msm_affine/(G[12])/(\d+)': lambda x, y: (f"msm_{x}_affine", int(y)),
+    r'.*/msm_affine/(G[12])/conversion/(\d+)': lambda x, y: (f"msm_{x}_affine_conversion", int(y)),
+    # the thread count depends on the machine, so it is left out of the name
+    r'.*/msm_parallel/(G[12])/\d+_threads/(\d+)': lambda x, y: (f"msm_{x}_parallel", int(y)),
+    r'.*/small_msm/(G1)/(\d+)': lambda x, y: (f"small_msm_{x}", int(y)),
+    r'.*/small_msm/(G1)/naive/(\d+)': lambda x, y: (f"small_msm_{x}_naive", int(y)),
+    r'.*/pedersen_commit/(G1)/(\d+)': lambda x, y: (f"pedersen_commit_{x}", int(y)),
+    r'.*/pedersen_commit/(G1)/precomputed/(\d+)': lambda x, y: (f"pedersen_commit_{x}_precomputed", int(y)),
+    r'.*/msm_fixed_bases/(G1)/(precomputed|multi_exp)/(\d+)': lambda x, y, z: (f"msm_{x}_fixed_bases_{y}", int(z)),
+    r'.*/msm_chunked/(G1)/chunk_(\d+)/(\d+)': lambda x, y, z: (f"msm_{x}_chunk_{y}", int(z)),
+    r'.*/msm_structured/(G1)/(distinct|powers|repeated)/(\d+)': lambda x, y, z: (f"msm_{x}_{y}", int(z)),
+    r'.*/msm_cache/(G1)/(warm|cold)/(\d+)': lambda x, y, z: (f"msm_{x}_{y}", int(z)),
+    r'.*/msm_then_prepare/(G2)/(msm|msm_prepare)/(\d+)': lambda x, y, z: (f"msm_then_prepare_{x}_{y}", int(z)),
+    r'.*/msm_bits/(G1)/(\d+)_bits/(\d+)': lambda x, y, z: (f"msm_{x}_{y}_bits", int(z)),
+    r'.*/msm_windows/(G1)/c(\d+)/(\d+)': lambda x, y, z: (f"msm_{x}_window_{y}", int(z)),
+    r'.*/msm_phases/(G1)/(accumulate|reduce)/(\d+)': lambda x, y, z: (f"msm_{x}_{y}", int(z)),
+    r'.*/kzg/(commit|open)/(\d+)': lambda x, y: (f"kzg_{x}", int(y)),
+    r'.*/kzg_batch_open/(\d+)': lambda x: ("kzg_batch_open", int(x)),
+    r'.*/ipa/(prove|verify)/(\d+)': lambda x, y: (f"ipa_{x}", int(y)),
+    r'.*/groth16_prover_msms/(\d+)': lambda x: ("groth16_prover_msms", int(x)),
+    r'.*/groth16_verify/(\d+)': lambda x: ("groth16_verify", int(x)),
+    r'.*/groth16_batch_verify/(batched|individual)/(\d+)': lambda x, y: (f"groth16_batch_verify_{x}", int(y)),
+    r'.*/lookup_commit/(\d+)': lambda x: ("lookup_commit", int(x)),
+    r'.*/folding_step/(\d+)': lambda x: ("folding_step", int(x)),
+    r'.*/batch_scalar_mul/(G1)/(table|naive)/(\d+)': lambda x, y, z: (f"batch_scalar_mul_{x}_{y}", int(z)),
+    r'.*/fixed_base/(G1)/w(\d+)$': lambda x, y: (f"mul_{x}_fixed_base_w{y}", 1),
+    r'.*/invert/fermat_(ct|vt)$': lambda x: (f"invert_fermat_{x}", 1),
+    r'.*/pow_ff/(\d+)_bits$': lambda x: (f"pow_ff_{x}_bits", 1),
+    r'.*/final_exp/(easy|hard)$': lambda x: (f"final_exp_{x}", 1),
+    r'.*/fri_fold/(\d+)': lambda x: ("fri_fold", int(x)),
+    r'.*/domain_setup/(\d+)': lambda x: (f"domain_setup", int(x)),
+    r'.*/vanishing_eval/(\d+)': lambda x: (f"vanishing_eval", int(x)),
+    r'.*/quotient/(\d+)': lambda x: ("quotient", int(x)),
+    r'.*/poly_(mul|eval)/(\d+)': lambda x, y: (f"poly_{x}", int(y)),
+    r'.*/(interpolate|barycentric_weights)/(\d+)': lambda x, y: (x, int(y)),
     r'.*/fft/(\d+)': lambda x: (f"fft", int(x)),
+    r'.*/fft/inverse/(\d+)': lambda x: (f"ifft", int(x)),
+    r'.*/fft/coset/(\d+)': lambda x: (f"coset_fft", int(x)),
+    r'.*/fft/coset_inverse/(\d+)': lambda x: (f"coset_ifft", int(x)),
+    r'.*/fft/twiddle/(\d+)': lambda x: ("fft_twiddle", int(x)),
+    r'.*/batch_invert/(\d+)': lambda x: (f"batch_invert", int(x)),
+    r'.*/powers/(\d+)': lambda x: ("powers_ff", int(x)),
+    r'.*/vec_(add|mul)/(\d+)': lambda x, y: (f"vec_{x}", int(y)),
+    r'.*/doubling/(G[12])': lambda x: (f"double_{x}", 1),
+    r'.*/mixed_add/(G[12])': lambda x: (f"mixed_add_{x}", 1),
+    r'.*/batch_affine_add/(G1)/(batched|independent)/(\d+)': lambda x, y, z: (f"batch_affine_add_{x}_{y}", int(z)),
+    r'.*/to_affine/(G[12])$': lambda x: (f"to_affine_{x}", 1),
+    r'.*/eq/(G[12])$': lambda x: (f"eq_{x}", 1),
+    r'.*/eq/(G[12])/same$': lambda x: (f"eq_{x}_same", 1),
+    r'.*/is_identity/(G[12])$': lambda x: (f"is_identity_{x}", 1),
+    r'.*/group_misc/(G[12])/(neg|ct_select)$': lambda x, y: (f"{y}_{x}", 1),
+    r'.*/batch_normalize/(G[12])/(\d+)': lambda x, y: (f"batch_normalize_{x}", int(y)),
+    r'.*/batch_normalize/(G[12])/individual/(\d+)': lambda x, y: (f"batch_normalize_{x}_individual", int(y)),
+    r'.*/scalar_mul/(G[12])$': lambda x: (f"mul_{x}", 1),
+    r'.*/scalar_mul/(G[12])/(glv|double_and_add)$': lambda x, y: (f"mul_{x}_{y}", 1),
+    r'.*/scalar_mul_small/(G1)/(\w+)/(scalar|double_and_add)$': lambda x, y, z: (f"mul_{x}_by_{y}_{z}", 1),
+    r'.*/rng/(scalar|G[12])$': lambda x: (f"rand_{x}", 1),
+    r'.*/convert/(scalar|G1)/(blst_to_ark|ark_to_blst)$': lambda x, y: (f"convert_{x}_{y}", 1),
+    r'.*/subgroup_check/(G[12])': lambda x: (f"subgroup_check_{x}", 1),
+    r'.*/vec_serialize/(G1)/(compressed|uncompressed)/(\d+)': lambda x, y, z: (f"vec_serialize_{x}_{y}", int(z)),
+    r'.*/serialize/(G[12])/(compressed|uncompressed)$': lambda x, y: (f"serialize_{x}_{y}", 1),
+    r'.*/deserialize/(G[12])/(compressed|uncompressed)/(checked|unchecked)$': lambda x, y, z: (f"deserialize_{x}_{y}_{z}", 1),
+    r'.*/vec_deserialize/(G1)/(compressed|uncompressed)/(checked|unchecked)/(\d+)': lambda x, y, z, w: (f"vec_deserialize_{x}_{y}_{z}", int(w)),
+    r'.*/srs_load/(G1)/(checked|unchecked)/(\d+)': lambda x, y, z: (f"srs_load_{x}_{y}", int(z)),
+    r'.*/clear_cofactor/(G[12])': lambda x: (f"clear_cofactor_{x}", 1),
+    r'.*/pairing/g2_prepare$': lambda: ("g2_prepare", 1),
+    r'.*/pairing/equality_check$': lambda: ("pairing_equality_check", 1),
+    r'.*/miller_loop_fixed_g2/(\d+)': lambda x: ("miller_loop_fixed_g2", int(x)),
+    r'.*/batch_pairing_verify/(batched|individual)/(\d+)': lambda x, y: (f"batch_pairing_verify_{x}", int(y)),
+    r'.*/(fp2|fp12)/(mul|invert)$': lambda x, y: (f"{y}_{x}", 1),
+    r'.*/hash_to_curve/(G[12])/.*_NU_$': lambda x: (f"encode_to_{x}", 1),
+    r'.*/hash_to_curve/(G[12])/': lambda x: (f"hash_to_{x}", 1),
+    r'.*/hash_to_field/(\d+)': lambda x: (f"hash_to_field", int(x)),
+    r'.*/fiat_shamir_challenge/(\d+)': lambda x: ("fiat_shamir_challenge", int(x)),
+    r'.*/merkle_build/(poseidon|sha256)/(\d+)': lambda x, y: (f"merkle_build_{x}", int(y)),
+    r'.*/merkle_cap/(poseidon|sha256)/cap_(\d+)/(\d+)': lambda x, y, z: (f"merkle_cap_{x}_{y}", int(z)),
+    r'.*/poseidon/(t\d+)/(\d+)': lambda x, y: (f"poseidon_{x}", int(y)),
+    r'.*/bls/verify$': lambda: ("bls_verify", 1),
+    r'.*/bls/aggregate_verify/(\d+)': lambda x: (f"bls_aggregate_verify", int(x)),
+    r'.*/bls/aggregate_verify_distinct/(\d+)': lambda x: (f"bls_aggregate_verify_distinct", int(x)),
+    # Gt is additive in zkalc: its multiplication is add_Gt, exponentiation is mul_Gt
+    r'.*/gt/mul$': lambda: ("add_Gt", 1),
+    r'.*/gt/exp$': lambda: ("mul_Gt", 1),
+    # msm_Gt already names the pairing product
+    r'.*/gt/msm/(\d+)': lambda x: ("gt_msm", int(x)),
 
     f'.*/({"|".join(op_ids)})': lambda x: (x, 1),
     r'.*/msm/(\d+)': lambda x: (f"msm_G1", int(x)),